@@ -0,0 +1,147 @@
+#[macro_use]
+extern crate criterion;
+extern crate specs;
+
+use criterion::{Bencher, Criterion};
+use specs::prelude::*;
+
+macro_rules! components {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Clone, Debug, Default)]
+            struct $name(u32);
+
+            impl Component for $name {
+                type Storage = VecStorage<Self>;
+            }
+        )*
+    };
+}
+
+components!(C0, C1, C2, C3, C4, C5, C6, C7);
+
+struct SysOne;
+
+impl<'a> System<'a> for SysOne {
+    type SystemData = WriteStorage<'a, C0>;
+
+    fn run(&mut self, mut c0: Self::SystemData) {
+        for c0 in (&mut c0).join() {
+            c0.0 += 1;
+        }
+    }
+}
+
+struct SysTwo;
+
+impl<'a> System<'a> for SysTwo {
+    type SystemData = (WriteStorage<'a, C0>, ReadStorage<'a, C1>);
+
+    fn run(&mut self, (mut c0, c1): Self::SystemData) {
+        for (c0, c1) in (&mut c0, &c1).join() {
+            c0.0 += c1.0;
+        }
+    }
+}
+
+struct SysFour;
+
+impl<'a> System<'a> for SysFour {
+    type SystemData = (
+        WriteStorage<'a, C0>,
+        ReadStorage<'a, C1>,
+        ReadStorage<'a, C2>,
+        ReadStorage<'a, C3>,
+    );
+
+    fn run(&mut self, (mut c0, c1, c2, c3): Self::SystemData) {
+        for (c0, c1, c2, c3) in (&mut c0, &c1, &c2, &c3).join() {
+            c0.0 += c1.0 + c2.0 + c3.0;
+        }
+    }
+}
+
+struct SysEight;
+
+impl<'a> System<'a> for SysEight {
+    type SystemData = (
+        WriteStorage<'a, C0>,
+        ReadStorage<'a, C1>,
+        ReadStorage<'a, C2>,
+        ReadStorage<'a, C3>,
+        ReadStorage<'a, C4>,
+        ReadStorage<'a, C5>,
+        ReadStorage<'a, C6>,
+        ReadStorage<'a, C7>,
+    );
+
+    fn run(&mut self, (mut c0, c1, c2, c3, c4, c5, c6, c7): Self::SystemData) {
+        for (c0, c1, c2, c3, c4, c5, c6, c7) in (&mut c0, &c1, &c2, &c3, &c4, &c5, &c6, &c7).join()
+        {
+            c0.0 += c1.0 + c2.0 + c3.0 + c4.0 + c5.0 + c6.0 + c7.0;
+        }
+    }
+}
+
+fn create_world(entities: usize, components: usize) -> World {
+    let mut w = World::new();
+
+    w.register::<C0>();
+    w.register::<C1>();
+    w.register::<C2>();
+    w.register::<C3>();
+    w.register::<C4>();
+    w.register::<C5>();
+    w.register::<C6>();
+    w.register::<C7>();
+
+    for _ in 0..entities {
+        let mut builder = w.create_entity().with(C0::default());
+        if components >= 2 {
+            builder = builder.with(C1::default());
+        }
+        if components >= 4 {
+            builder = builder.with(C2::default()).with(C3::default());
+        }
+        if components >= 8 {
+            builder = builder
+                .with(C4::default())
+                .with(C5::default())
+                .with(C6::default())
+                .with(C7::default());
+        }
+        builder.build();
+    }
+
+    w
+}
+
+fn dispatch(b: &mut Bencher, entities: usize, components: usize) {
+    let mut w = create_world(entities, components);
+    let builder = DispatcherBuilder::new();
+    let mut dispatcher = match components {
+        1 => builder.with(SysOne, "sys", &[]),
+        2 => builder.with(SysTwo, "sys", &[]),
+        4 => builder.with(SysFour, "sys", &[]),
+        _ => builder.with(SysEight, "sys", &[]),
+    }
+    .build();
+    dispatcher.setup(&mut w);
+
+    b.iter(|| dispatcher.dispatch(&w));
+}
+
+fn dispatch_benchmarks(c: &mut Criterion) {
+    for &entities in &[1, 10, 100, 1000] {
+        for &components in &[1, 2, 4, 8] {
+            c.bench_function(
+                &format!("dispatch {} entities {} components", entities, components),
+                move |b| dispatch(b, entities, components),
+            );
+        }
+    }
+}
+
+criterion_group!(dispatch_group, dispatch_benchmarks);
+
+criterion_main!(dispatch_group);
@@ -10,7 +10,7 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
 };
 
-use crate::world::{Entity, Generation};
+use crate::world::{Entity, Generation, Index};
 
 /// A boxed error implementing `Debug`, `Display` and `Error`.
 pub struct BoxedErr(pub Box<dyn StdError + Send + Sync + 'static>);
@@ -55,6 +55,8 @@ pub enum Error {
     Custom(BoxedErr),
     /// Wrong generation error.
     WrongGeneration(WrongGeneration),
+    /// The entity already has a component of this type.
+    ComponentExists(ComponentExists),
 }
 
 impl Display for Error {
@@ -62,6 +64,7 @@ impl Display for Error {
         match *self {
             Error::Custom(ref e) => write!(f, "Custom: {}", e),
             Error::WrongGeneration(ref e) => write!(f, "Wrong generation: {}", e),
+            Error::ComponentExists(ref e) => write!(f, "Component exists: {}", e),
         }
     }
 }
@@ -78,11 +81,18 @@ impl From<WrongGeneration> for Error {
     }
 }
 
+impl From<ComponentExists> for Error {
+    fn from(e: ComponentExists) -> Self {
+        Error::ComponentExists(e)
+    }
+}
+
 impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         let e = match *self {
             Error::Custom(ref e) => e.as_ref(),
             Error::WrongGeneration(ref e) => e,
+            Error::ComponentExists(ref e) => e,
         };
 
         Some(e)
@@ -113,6 +123,84 @@ impl Display for WrongGeneration {
 
 impl StdError for WrongGeneration {}
 
+/// Error returned when a component can't be added to an entity because it
+/// already has one of the same type.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ComponentExists {
+    /// The type name of the component.
+    pub component: &'static str,
+    /// The entity that already has the component.
+    pub entity: Entity,
+}
+
+impl Display for ComponentExists {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "Entity {:?} already has a component of type `{}`",
+            self.entity, self.component
+        )
+    }
+}
+
+impl StdError for ComponentExists {}
+
+/// Error returned when converting a generation id of `0`, which is neither
+/// alive nor dead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZeroGeneration;
+
+impl Display for ZeroGeneration {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "0 is not a valid generation")
+    }
+}
+
+impl StdError for ZeroGeneration {}
+
+/// An invariant of the entity allocator that has been violated, see
+/// `EntitiesRes::validate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The index is marked as alive, but its generation is dead.
+    DeadGeneration(Index),
+    /// The index is both alive and pending atomic creation.
+    AliveAndRaised(Index),
+    /// The index is pending atomic deletion, but is neither alive nor pending
+    /// creation.
+    KilledNotAllocated(Index),
+    /// The index is in use, but not below the allocated id high-water mark.
+    OutOfBounds(Index),
+    /// The index is available for reuse, but is still in use.
+    CachedInUse(Index),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            ValidationError::DeadGeneration(id) => {
+                write!(f, "index {} is alive, but has a dead generation", id)
+            }
+            ValidationError::AliveAndRaised(id) => {
+                write!(f, "index {} is alive and pending creation", id)
+            }
+            ValidationError::KilledNotAllocated(id) => write!(
+                f,
+                "index {} is pending deletion, but has not been allocated",
+                id
+            ),
+            ValidationError::OutOfBounds(id) => {
+                write!(f, "index {} exceeds the allocated id range", id)
+            }
+            ValidationError::CachedInUse(id) => {
+                write!(f, "index {} is available for reuse, but still in use", id)
+            }
+        }
+    }
+}
+
+impl StdError for ValidationError {}
+
 /// Reexport of `Infallible` for a smoother transition.
 #[deprecated = "Use std::convert::Infallible instead"]
 pub type NoError = Infallible;
@@ -203,6 +203,7 @@ pub mod changeset;
 pub mod error;
 pub mod join;
 pub mod prelude;
+pub mod snapshot;
 pub mod storage;
 pub mod world;
 
@@ -225,6 +226,7 @@ pub use crate::join::ParJoin;
 pub use crate::{
     changeset::ChangeSet,
     join::{Join, LendJoin},
+    snapshot::ComponentSnapshot,
     storage::{
         DefaultVecStorage, DenseVecStorage, FlaggedStorage, HashMapStorage, NullStorage,
         ReadStorage, Storage, Tracked, VecStorage, WriteStorage,
@@ -0,0 +1,164 @@
+//! Provides a snapshot of components that can be collected from an iterator.
+
+use std::{collections::BTreeMap, iter::FromIterator, ops::DerefMut};
+
+use crate::{
+    storage::{MaskedStorage, Storage},
+    world::{Component, Entity},
+};
+
+/// A set of components keyed by entity, which can be collected from an
+/// iterator of `(Entity, T)` pairs and later applied to a storage.
+///
+/// If an entity occurs more than once, the last component wins.
+///
+/// ### Example
+///
+/// ```rust
+/// # extern crate specs;
+/// # use specs::{prelude::*, snapshot::ComponentSnapshot};
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Health(i32);
+///
+/// impl Component for Health {
+///     type Storage = DenseVecStorage<Self>;
+/// }
+///
+/// # fn main() {
+/// # let mut world = World::new();
+/// # world.register::<Health>();
+///
+/// let a = world.create_entity().build();
+/// let b = world.create_entity().build();
+///
+/// let snapshot = vec![(a, Health(100)), (b, Health(200)), (a, Health(50))]
+///     .into_iter()
+///     .collect::<ComponentSnapshot<Health>>();
+///
+/// let mut storage = world.write_storage::<Health>();
+/// snapshot.apply(&mut storage);
+/// assert_eq!(storage.get(a), Some(&Health(50)));
+/// assert_eq!(storage.get(b), Some(&Health(200)));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ComponentSnapshot<T> {
+    components: BTreeMap<Entity, T>,
+}
+
+impl<T> Default for ComponentSnapshot<T> {
+    fn default() -> Self {
+        Self {
+            components: Default::default(),
+        }
+    }
+}
+
+impl<T> ComponentSnapshot<T> {
+    /// Create a new, empty snapshot
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a component to the snapshot, returning the component previously
+    /// stored for `entity`, if any.
+    pub fn insert(&mut self, entity: Entity, component: T) -> Option<T> {
+        self.components.insert(entity, component)
+    }
+
+    /// Returns the component stored for `entity`, if any.
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        self.components.get(&entity)
+    }
+
+    /// Returns the number of components in the snapshot.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if the snapshot contains no components.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Inserts all components of this snapshot into `storage`, overwriting
+    /// existing components. Components of dead entities are skipped.
+    pub fn apply<D>(self, storage: &mut Storage<'_, T, D>)
+    where
+        T: Component,
+        D: DerefMut<Target = MaskedStorage<T>>,
+    {
+        storage.extend(self.components);
+    }
+}
+
+impl<T> FromIterator<(Entity, T)> for ComponentSnapshot<T> {
+    fn from_iter<I: IntoIterator<Item = (Entity, T)>>(iter: I) -> Self {
+        Self {
+            components: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Entity, T)> for ComponentSnapshot<T> {
+    fn extend<I: IntoIterator<Item = (Entity, T)>>(&mut self, iter: I) {
+        self.components.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for ComponentSnapshot<T> {
+    type IntoIter = std::collections::btree_map::IntoIter<Entity, T>;
+    type Item = (Entity, T);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentSnapshot;
+    use crate::{
+        storage::VecStorage,
+        world::{Builder, Component, WorldExt},
+    };
+    use shred::World;
+
+    #[derive(Debug, PartialEq)]
+    pub struct Health(i32);
+
+    impl Component for Health {
+        type Storage = VecStorage<Self>;
+    }
+
+    #[test]
+    fn apply() {
+        let mut world = World::new();
+        world.register::<Health>();
+
+        let a = world.create_entity().with(Health(1)).build();
+        let b = world.create_entity().build();
+        let c = world.create_entity().with(Health(3)).build();
+        let dead = world.create_entity().build();
+        world.delete_entity(dead).unwrap();
+
+        let snapshot = vec![
+            (a, Health(10)),
+            (b, Health(20)),
+            (dead, Health(0)),
+            (b, Health(30)),
+        ]
+        .into_iter()
+        .collect::<ComponentSnapshot<_>>();
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(snapshot.get(b), Some(&Health(30)));
+
+        let mut storage = world.write_storage::<Health>();
+        snapshot.apply(&mut storage);
+        assert_eq!(storage.get(a), Some(&Health(10)));
+        assert_eq!(storage.get(b), Some(&Health(30)));
+        assert_eq!(storage.get(c), Some(&Health(3)));
+        assert_eq!(storage.get(dead), None);
+    }
+}
@@ -1,7 +1,7 @@
-use shred::{Fetch, FetchMut, MetaTable, ResourceId, SystemData, World};
+use shred::{Fetch, FetchMut, ResourceId, SystemData, World};
 
 use crate::{
-    storage::{AnyStorage, MaskedStorage, Storage, TryDefault},
+    storage::{register_meta, MaskedStorage, Storage, TryDefault},
     world::{Component, EntitiesRes},
 };
 
@@ -123,8 +123,7 @@ where
     fn setup(res: &mut World) {
         res.entry::<MaskedStorage<T>>()
             .or_insert_with(|| MaskedStorage::new(<T::Storage as TryDefault>::unwrap_default()));
-        res.fetch_mut::<MetaTable<dyn AnyStorage>>()
-            .register::<MaskedStorage<T>>();
+        register_meta::<T>(res);
     }
 
     fn fetch(res: &'a World) -> Self {
@@ -210,8 +209,7 @@ where
     fn setup(res: &mut World) {
         res.entry::<MaskedStorage<T>>()
             .or_insert_with(|| MaskedStorage::new(<T::Storage as TryDefault>::unwrap_default()));
-        res.fetch_mut::<MetaTable<dyn AnyStorage>>()
-            .register::<MaskedStorage<T>>();
+        register_meta::<T>(res);
     }
 
     fn fetch(res: &'a World) -> Self {
@@ -54,6 +54,30 @@ where
         }
     }
 
+    /// Returns the component of an `Entity`, inserting `default` first if it
+    /// has none. Shorthand for `entry(e)?.or_insert(default)`.
+    pub fn get_or_insert(
+        &mut self,
+        e: Entity,
+        default: T,
+    ) -> Result<AccessMutReturn<'_, T>, WrongGeneration> {
+        self.get_or_insert_with(e, || default)
+    }
+
+    /// Returns the component of an `Entity`, inserting the result of
+    /// `default` first if it has none. Shorthand for
+    /// `entry(e)?.or_insert_with(default)`.
+    pub fn get_or_insert_with<F>(
+        &mut self,
+        e: Entity,
+        default: F,
+    ) -> Result<AccessMutReturn<'_, T>, WrongGeneration>
+    where
+        F: FnOnce() -> T,
+    {
+        Ok(self.entry(e)?.or_insert_with(default))
+    }
+
     /// Returns a [`LendJoin`]-able structure that yields all indices, returning
     /// [`StorageEntry`] for all elements
     ///
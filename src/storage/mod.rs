@@ -14,26 +14,28 @@ pub use self::{
         BTreeStorage, DefaultVecStorage, DenseVecStorage, HashMapStorage, NullStorage, SliceAccess,
         VecStorage,
     },
-    track::{ComponentEvent, Tracked},
+    track::{ComponentChanges, ComponentEvent, Tracked},
 };
 
 use std::{
     self,
+    any::{Any, TypeId},
+    borrow::Cow,
     marker::PhantomData,
     ops::{Deref, DerefMut, Not},
 };
 
 use hibitset::{BitSet, BitSetLike, BitSetNot};
-use shred::{CastFrom, Fetch};
+use shred::{CastFrom, Fetch, MetaTable, World};
 
 #[nougat::gat(Type)]
 use crate::join::LendJoin;
 #[cfg(feature = "parallel")]
-use crate::join::ParJoin;
+use crate::join::{JoinParIter, ParJoin};
 use crate::{
     error::{Error, WrongGeneration},
-    join::{Join, RepeatableLendGet},
-    world::{Component, EntitiesRes, Entity, Index},
+    join::{Join, JoinIter, RepeatableLendGet},
+    world::{Component, EntitiesRes, Entity, Index, TypeRegistry},
 };
 
 use self::drain::Drain;
@@ -134,6 +136,109 @@ where
     }
 }
 
+/// Type-erased access to a storage, used by the `WorldExt` methods which
+/// work on all registered storages.
+pub(crate) trait AnyStorageExt {
+    /// Returns the `TypeId` of the component kept in this storage.
+    fn component_type_id(&self) -> TypeId;
+
+    /// Returns the type name of the component kept in this storage.
+    fn component_name(&self) -> &'static str;
+
+    /// Returns the number of components in this storage.
+    fn count(&self) -> usize;
+
+    /// Returns the component stored at `id`, if any. The generation of the
+    /// entity is not checked, so callers have to make sure it is alive.
+    fn get_any(&self, id: Index) -> Option<&dyn Any>;
+
+    /// Moves the component of `entity` (if any) into the storage of the same
+    /// component type in `target`, attaching it to `target_entity`.
+    ///
+    /// If the component type is not registered in `target`, the component is
+    /// dropped and a warning is logged.
+    fn move_to(&mut self, entity: Entity, target: &World, target_entity: Entity);
+}
+
+// SAFETY: Returned pointer has a vtable valid for `T` and retains the same
+// address/provenance.
+unsafe impl<T> CastFrom<T> for dyn AnyStorageExt
+where
+    T: AnyStorageExt + 'static,
+{
+    fn cast(t: *mut T) -> *mut Self {
+        t
+    }
+}
+
+impl<T> AnyStorageExt for MaskedStorage<T>
+where
+    T: Component,
+{
+    fn component_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn component_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn count(&self) -> usize {
+        (&self.mask).iter().count()
+    }
+
+    fn get_any(&self, id: Index) -> Option<&dyn Any> {
+        if self.mask.contains(id) {
+            // SAFETY: We checked the mask.
+            Some(unsafe { self.inner.get(id) })
+        } else {
+            None
+        }
+    }
+
+    fn move_to(&mut self, entity: Entity, target: &World, target_entity: Entity) {
+        let component = match self.remove(entity.id()) {
+            Some(component) => component,
+            None => return,
+        };
+
+        match target.try_fetch_mut::<MaskedStorage<T>>() {
+            Some(data) => {
+                let mut storage = Storage::new(target.fetch(), data);
+                if let Err(e) = storage.insert(target_entity, component) {
+                    log::warn!(
+                        "Failed to move component `{}`: {}",
+                        std::any::type_name::<T>(),
+                        e
+                    );
+                }
+            }
+            None => log::warn!(
+                "Dropping component `{}` of {:?}: it is not registered in the target world",
+                std::any::type_name::<T>(),
+                entity
+            ),
+        }
+    }
+}
+
+/// Registers `MaskedStorage<T>` in the `MetaTable`s of the world, so it is
+/// found by the methods working on all storages, and adds `T` to the
+/// `TypeRegistry`.
+pub(crate) fn register_meta<T: Component>(world: &mut World) {
+    world
+        .fetch_mut::<MetaTable<dyn AnyStorage>>()
+        .register::<MaskedStorage<T>>();
+    world
+        .entry::<MetaTable<dyn AnyStorageExt>>()
+        .or_insert_with(Default::default)
+        .register::<MaskedStorage<T>>();
+    world
+        .entry::<TypeRegistry>()
+        .or_insert_with(Default::default)
+        .register::<T>();
+}
+
 /// This is a marker trait which requires you to uphold the following guarantee:
 ///
 /// # Safety
@@ -285,6 +390,16 @@ where
         }
     }
 
+    /// Returns the component of an `Entity`, or `T::default()` if it has
+    /// none.
+    pub fn get_or_default(&self, e: Entity) -> Cow<'_, T>
+    where
+        T: Clone + Default,
+    {
+        self.get(e)
+            .map_or_else(|| Cow::Owned(T::default()), Cow::Borrowed)
+    }
+
     /// Computes the number of elements this `Storage` contains by counting the
     /// bits in the bit set. This operation will never be performed in
     /// constant time.
@@ -308,6 +423,14 @@ where
     pub fn mask(&self) -> &BitSet {
         &self.data.mask
     }
+
+    /// Returns an iterator over the components of all live entities in this
+    /// storage, together with the entity each one belongs to.
+    ///
+    /// This is a shorthand for `(&entities, &storage).join()`.
+    pub fn iter(&self) -> JoinIter<(&EntitiesRes, &Self)> {
+        (&*self.entities, self).join()
+    }
 }
 
 impl<'e, T, D> Storage<'e, T, D>
@@ -439,11 +562,54 @@ where
         }
     }
 
+    /// Exchanges the components of two entities. If only one of them has a
+    /// component, it is moved to the other one.
+    ///
+    /// Returns an error if either entity is dead, in which case the storage
+    /// is left unchanged.
+    pub fn swap(&mut self, a: Entity, b: Entity) -> Result<(), Error> {
+        for &e in &[a, b] {
+            if !self.entities.is_alive(e) {
+                return Err(Error::WrongGeneration(WrongGeneration {
+                    action: "swap components of entity",
+                    actual_gen: self.entities.entity(e.id()).gen(),
+                    entity: e,
+                }));
+            }
+        }
+
+        match self.data.remove(a.id()) {
+            Some(component) => {
+                if let Some(other) = self.insert(b, component)? {
+                    self.insert(a, other)?;
+                }
+            }
+            None => {
+                if let Some(other) = self.data.remove(b.id()) {
+                    self.insert(a, other)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Clears the contents of the storage.
     pub fn clear(&mut self) {
         self.data.clear();
     }
 
+    /// Replaces the contents of the storage with the given components.
+    ///
+    /// Components of dead entities are skipped and a warning is logged.
+    pub fn replace_all<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Entity, T)>,
+    {
+        self.clear();
+        self.extend(iter);
+    }
+
     /// Creates a draining storage wrapper which can be `.join`ed
     /// to get a draining iterator.
     pub fn drain(&mut self) -> Drain<T> {
@@ -451,6 +617,32 @@ where
             data: &mut self.data,
         }
     }
+
+    /// Retains only the components for which the predicate returns `true`,
+    /// removing all others, similar to `Vec::retain`.
+    ///
+    /// The predicate gets mutable access to every component, so flagged
+    /// storages will emit a modification event for each of them.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Entity, &mut T) -> bool,
+    {
+        let mut remove = BitSet::new();
+        {
+            let (mask, inner) = self.data.open_mut();
+            for id in mask.iter() {
+                // SAFETY: We checked the mask (`id` is yielded from it).
+                let mut component = unsafe { inner.get_mut(id) };
+                if !f(self.entities.entity(id), component.access_mut()) {
+                    remove.add(id);
+                }
+            }
+        }
+
+        for id in (&remove).iter() {
+            self.data.remove(id);
+        }
+    }
 }
 
 impl<'a, T, D: Clone> Clone for Storage<'a, T, D> {
@@ -459,6 +651,23 @@ impl<'a, T, D: Clone> Clone for Storage<'a, T, D> {
     }
 }
 
+/// Inserts every `(Entity, T)` pair, overwriting existing components.
+///
+/// Pairs whose entity is dead are skipped and a warning is logged.
+impl<'e, T, D> Extend<(Entity, T)> for Storage<'e, T, D>
+where
+    T: Component,
+    D: DerefMut<Target = MaskedStorage<T>>,
+{
+    fn extend<I: IntoIterator<Item = (Entity, T)>>(&mut self, iter: I) {
+        for (entity, component) in iter {
+            if let Err(e) = self.insert(entity, component) {
+                log::warn!("Failed to extend storage: {}", e);
+            }
+        }
+    }
+}
+
 impl<'a, 'e, T, D> Not for &'a Storage<'e, T, D>
 where
     T: Component,
@@ -714,6 +923,25 @@ where
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<'e, T, D> Storage<'e, T, D>
+where
+    T: Component,
+    D: DerefMut<Target = MaskedStorage<T>>,
+{
+    /// Returns a parallel iterator over the indices and mutable components of
+    /// this storage.
+    ///
+    /// This is a shorthand for joining a copy of the mask with `&mut self`,
+    /// so it is available for the same storages as `par_join`.
+    pub fn par_iter_mut<'a>(&'a mut self) -> JoinParIter<(BitSet, &'a mut Self)>
+    where
+        &'a mut Self: ParJoin,
+    {
+        (self.mask().clone(), self).par_join()
+    }
+}
+
 /// Tries to create a default value, returns an `Err` with the name of the
 /// storage and/or component if there's no default.
 pub trait TryDefault: Sized {
@@ -140,7 +140,7 @@ mod test {
         type Storage = NullStorage<Self>;
     }
 
-    #[derive(PartialEq, Eq, Debug, Default)]
+    #[derive(Clone, PartialEq, Eq, Debug, Default)]
     struct Cvec(u32);
     impl From<u32> for Cvec {
         fn from(v: u32) -> Cvec {
@@ -764,6 +764,31 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn par_iter_mut() {
+        use rayon::iter::ParallelIterator;
+
+        let mut w = World::new();
+        w.register::<Cvec>();
+        let mut s: Storage<Cvec, _> = w.write_storage();
+
+        for i in 0..ITERATIONS {
+            s.insert(Entity::new(i, Generation::new(1)), i.into())
+                .unwrap();
+        }
+
+        let mut expected: Vec<_> = (&s).join().map(|c| c.0 * 2 + 1).collect();
+        s.par_iter_mut().for_each(|(id, c)| {
+            assert_eq!(id, c.0);
+            c.0 = c.0 * 2 + 1;
+        });
+        let mut actual: Vec<_> = (&s).join().map(|c| c.0).collect();
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn storage_entry() {
         let mut w = World::new();
@@ -858,6 +883,155 @@ mod test {
         }
     }
 
+    #[test]
+    fn storage_iter() {
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let a = w.create_entity().with(Cvec(1)).build();
+        w.create_entity().build();
+        let b = w.create_entity().with(Cvec(3)).build();
+
+        let s = w.read_storage::<Cvec>();
+        let items: Vec<(Entity, &Cvec)> = s.iter().collect();
+        assert_eq!(items, vec![(a, &Cvec(1)), (b, &Cvec(3))]);
+    }
+
+    #[test]
+    fn storage_retain() {
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let entities: Vec<Entity> = (0..10)
+            .map(|i| w.create_entity().with(Cvec(i)).build())
+            .collect();
+
+        let mut s = w.write_storage::<Cvec>();
+        s.retain(|entity, comp| {
+            assert_eq!(entity, entities[comp.0 as usize]);
+            comp.0 += 1;
+            comp.0 % 2 == 0
+        });
+
+        assert_eq!(s.count(), 5);
+        for (i, &entity) in entities.iter().enumerate() {
+            let value = i as u32 + 1;
+            if value % 2 == 0 {
+                assert_eq!(s.get(entity), Some(&Cvec(value)));
+            } else {
+                assert_eq!(s.get(entity), None);
+            }
+        }
+    }
+
+    #[test]
+    fn storage_extend() {
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let entities: Vec<Entity> = (0..5).map(|_| w.create_entity().build()).collect();
+        let dead = w.create_entity().build();
+        w.delete_entity(dead).unwrap();
+
+        let mut s = w.write_storage::<Cvec>();
+        s.insert(entities[0], Cvec(100)).unwrap();
+        s.extend(
+            entities
+                .iter()
+                .enumerate()
+                .map(|(i, &e)| (e, Cvec(i as u32)))
+                .chain(std::iter::once((dead, Cvec(42)))),
+        );
+
+        assert_eq!(s.count(), 5);
+        for (i, &entity) in entities.iter().enumerate() {
+            assert_eq!(s.get(entity), Some(&Cvec(i as u32)));
+        }
+        assert_eq!(s.get(dead), None);
+    }
+
+    #[test]
+    fn storage_swap() {
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let a = w.create_entity().with(Cvec(1)).build();
+        let b = w.create_entity().with(Cvec(2)).build();
+        let c = w.create_entity().build();
+        let dead = w.create_entity().build();
+        w.delete_entity(dead).unwrap();
+
+        let mut s = w.write_storage::<Cvec>();
+        s.swap(a, b).unwrap();
+        assert_eq!(s.get(a), Some(&Cvec(2)));
+        assert_eq!(s.get(b), Some(&Cvec(1)));
+
+        s.swap(a, c).unwrap();
+        assert_eq!(s.get(a), None);
+        assert_eq!(s.get(c), Some(&Cvec(2)));
+
+        s.swap(a, c).unwrap();
+        assert_eq!(s.get(a), Some(&Cvec(2)));
+        assert_eq!(s.get(c), None);
+
+        assert!(s.swap(b, dead).is_err());
+        assert_eq!(s.get(b), Some(&Cvec(1)));
+    }
+
+    #[test]
+    fn storage_replace_all() {
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let a = w.create_entity().with(Cvec(1)).build();
+        let b = w.create_entity().with(Cvec(2)).build();
+        let dead = w.create_entity().build();
+        w.delete_entity(dead).unwrap();
+
+        let mut s = w.write_storage::<Cvec>();
+        s.replace_all(vec![(b, Cvec(20)), (dead, Cvec(30))]);
+
+        assert_eq!(s.count(), 1);
+        assert_eq!(s.get(a), None);
+        assert_eq!(s.get(b), Some(&Cvec(20)));
+        assert_eq!(s.get(dead), None);
+    }
+
+    #[test]
+    fn storage_get_or_default() {
+        use std::borrow::Cow;
+
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let a = w.create_entity().with(Cvec(5)).build();
+        let b = w.create_entity().build();
+
+        let s = w.read_storage::<Cvec>();
+        assert!(matches!(s.get_or_default(a), Cow::Borrowed(&Cvec(5))));
+        assert!(matches!(s.get_or_default(b), Cow::Owned(Cvec(0))));
+    }
+
+    #[test]
+    fn storage_get_or_insert() {
+        let mut w = World::new();
+        w.register::<Cvec>();
+
+        let a = w.create_entity().with(Cvec(5)).build();
+        let b = w.create_entity().build();
+        let dead = w.create_entity().build();
+        w.delete_entity(dead).unwrap();
+
+        let mut s = w.write_storage::<Cvec>();
+        s.get_or_insert(a, Cvec(1)).unwrap().0 += 1;
+        s.get_or_insert_with(b, || Cvec(10)).unwrap().0 += 1;
+        assert_eq!(s.get(a), Some(&Cvec(6)));
+        assert_eq!(s.get(b), Some(&Cvec(11)));
+
+        assert!(s.get_or_insert(dead, Cvec(0)).is_err());
+        assert_eq!(s.get(dead), None);
+    }
+
     #[test]
     fn storage_mask() {
         use crate::join::Join;
@@ -902,6 +1076,34 @@ mod test {
         assert_eq!((s1.mask()).par_join().count(), 50);
     }
 
+    #[test]
+    fn component_changes() {
+        let mut w = World::new();
+        w.register::<FlaggedCvec>();
+
+        let mut s: Storage<FlaggedCvec, _> = w.write_storage();
+        let mut reader_id = s.register_reader();
+        let mut changes = ComponentChanges::new();
+
+        let a = w.entities().create();
+        let b = w.entities().create();
+        s.insert(a, 1.into()).unwrap();
+        s.insert(b, 2.into()).unwrap();
+        s.get_mut(a).unwrap().0 += 1;
+        s.remove(b);
+
+        changes.update(&s, &mut reader_id);
+        assert!(changes.added().contains(a.id()) && changes.added().contains(b.id()));
+        assert!(changes.modified().contains(a.id()) && !changes.modified().contains(b.id()));
+        assert!(!changes.removed().contains(a.id()) && changes.removed().contains(b.id()));
+
+        changes.clear();
+        changes.update(&s, &mut reader_id);
+        assert!(changes.added().is_empty());
+        assert!(changes.modified().is_empty());
+        assert!(changes.removed().is_empty());
+    }
+
     #[test]
     fn flagged() {
         use crate::join::Join;
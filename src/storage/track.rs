@@ -1,5 +1,6 @@
 use std::ops::{Deref, DerefMut};
 
+use hibitset::BitSet;
 use shrev::{EventChannel, ReaderId};
 
 use crate::{
@@ -42,6 +43,67 @@ pub enum ComponentEvent {
     Removed(Index),
 }
 
+/// The indices of the components that were inserted, modified or removed,
+/// collected from the events of a `Tracked` storage.
+///
+/// Call `update` every frame to read the new events and `clear` once they
+/// have been processed. The sets can be joined over directly.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentChanges {
+    added: BitSet,
+    modified: BitSet,
+    removed: BitSet,
+}
+
+impl ComponentChanges {
+    /// Creates an empty set of changes.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Reads all new events of `storage` for `reader` and adds them to the
+    /// sets.
+    pub fn update<T, D>(
+        &mut self,
+        storage: &Storage<'_, T, D>,
+        reader: &mut ReaderId<ComponentEvent>,
+    ) where
+        T: Component,
+        T::Storage: Tracked,
+        D: Deref<Target = MaskedStorage<T>>,
+    {
+        for event in storage.channel().read(reader) {
+            match *event {
+                ComponentEvent::Inserted(id) => self.added.add(id),
+                ComponentEvent::Modified(id) => self.modified.add(id),
+                ComponentEvent::Removed(id) => self.removed.add(id),
+            };
+        }
+    }
+
+    /// Returns the indices of inserted components.
+    pub fn added(&self) -> &BitSet {
+        &self.added
+    }
+
+    /// Returns the indices of modified components.
+    pub fn modified(&self) -> &BitSet {
+        &self.modified
+    }
+
+    /// Returns the indices of removed components.
+    pub fn removed(&self) -> &BitSet {
+        &self.removed
+    }
+
+    /// Clears all sets.
+    pub fn clear(&mut self) {
+        self.added.clear();
+        self.modified.clear();
+        self.removed.clear();
+    }
+}
+
 impl<'e, T, D> Storage<'e, T, D>
 where
     T: Component,
@@ -1,5 +1,6 @@
 use std::{
     fmt,
+    hash::{Hash, Hasher},
     num::NonZeroI32,
     sync::atomic::{AtomicUsize, Ordering},
 };
@@ -12,8 +13,8 @@ use crate::join::LendJoin;
 #[cfg(feature = "parallel")]
 use crate::join::ParJoin;
 use crate::{
-    error::WrongGeneration,
-    join::{Join, RepeatableLendGet},
+    error::{ValidationError, WrongGeneration, ZeroGeneration},
+    join::{Join, JoinIter, RepeatableLendGet},
     storage::WriteStorage,
     world::Component,
 };
@@ -21,6 +22,10 @@ use crate::{
 /// An index is basically the id of an `Entity`.
 pub type Index = u32;
 
+/// The number of indices the bit sets of the allocator can hold.
+const MAX_INDICES: usize =
+    BitSet::LAYER2_GRANULARITY * BitSet::BITS_PER_USIZE * BitSet::BITS_PER_USIZE;
+
 /// A wrapper for a read `Entities` resource.
 /// Note that this is just `Read<Entities>`, so
 /// you can easily use it in your system:
@@ -111,9 +116,7 @@ impl Allocator {
     pub(crate) fn del_err(&self, e: Entity) -> WrongGeneration {
         WrongGeneration {
             action: "delete",
-            actual_gen: self.generations[e.id() as usize]
-                .0
-                .unwrap_or_else(Generation::one),
+            actual_gen: self.generation(e.id()).unwrap_or_else(Generation::one),
             entity: e,
         }
     }
@@ -147,6 +150,12 @@ impl Allocator {
         Entity(id, gen)
     }
 
+    /// Returns the high-water mark of allocated indices. Every index that
+    /// was ever handed out (atomically or not) is smaller than this value.
+    pub fn max_allocated_id(&self) -> Index {
+        self.max_id.load(Ordering::Relaxed) as Index
+    }
+
     /// Allocate a new entity
     pub fn allocate_atomic(&self) -> Entity {
         let id = self.cache.pop_atomic().unwrap_or_else(|| {
@@ -178,9 +187,39 @@ impl Allocator {
         Entity(id as Index, gen)
     }
 
+    /// Allocate a new entity at the given index, returning `None` if it is
+    /// already in use or too large to be tracked.
+    ///
+    /// Unused indices below `id` are kept available for later allocations.
+    pub fn allocate_at(&mut self, id: Index) -> Option<Entity> {
+        if id as usize >= MAX_INDICES || self.alive.contains(id) || self.raised.contains(id) {
+            return None;
+        }
+
+        let max_id = *self.max_id.get_mut();
+        if id as usize >= max_id {
+            self.cache.extend(max_id as Index..id);
+            *self.max_id.get_mut() = (id as usize)
+                .checked_add(1)
+                .expect("No entity left to allocate");
+        } else {
+            self.cache.remove(id);
+        }
+
+        self.update_generation_length(id as usize);
+        self.alive.add(id);
+        let gen = self.generations[id as usize].raise();
+
+        Some(Entity(id, gen))
+    }
+
     /// Maintains the allocated entities, mainly dealing with atomically
-    /// allocated or killed entities.
-    pub fn merge(&mut self) -> Vec<Entity> {
+    /// allocated or killed entities. `on_create` is called for every
+    /// atomically allocated entity that is now alive.
+    pub fn merge_with<F>(&mut self, mut on_create: F) -> Vec<Entity>
+    where
+        F: FnMut(Entity),
+    {
         use hibitset::BitSetLike;
 
         let mut deleted = vec![];
@@ -189,8 +228,9 @@ impl Allocator {
         self.update_generation_length(max_id + 1);
 
         for i in (&self.raised).iter() {
-            self.generations[i as usize].raise();
+            let gen = self.generations[i as usize].raise();
             self.alive.add(i);
+            on_create(Entity(i, gen));
         }
         self.raised.clear();
 
@@ -202,10 +242,131 @@ impl Allocator {
         self.killed.clear();
 
         self.cache.extend(deleted.iter().map(|e| e.0));
+        debug_assert_eq!(self.validate(), Ok(()));
 
         deleted
     }
 
+    /// Returns the entities that the next `merge_with` would create and delete,
+    /// without changing any state.
+    pub fn pending(&self) -> (Vec<Entity>, Vec<Entity>) {
+        use hibitset::BitSetLike;
+
+        let raised_gen = |i: Index| {
+            self.generations
+                .get(i as usize)
+                .copied()
+                .unwrap_or(ZeroableGeneration(None))
+                .raised()
+        };
+
+        let created = (&self.raised)
+            .iter()
+            .map(|i| Entity(i, raised_gen(i)))
+            .collect();
+        let deleted = (&self.killed)
+            .iter()
+            .map(|i| {
+                let gen = if self.raised.contains(i) {
+                    raised_gen(i)
+                } else {
+                    self.generations[i as usize].0.unwrap()
+                };
+                Entity(i, gen)
+            })
+            .collect();
+
+        (created, deleted)
+    }
+
+    /// Iterates over all entities that are alive, not including atomically
+    /// created ones that have not been merged yet.
+    pub fn iter_alive(&self) -> impl Iterator<Item = Entity> + '_ {
+        use hibitset::BitSetLike;
+
+        (&self.alive).iter().map(move |id| {
+            let gen = self.generations[id as usize]
+                .0
+                .expect("Bug: alive entity without generation");
+            Entity(id, gen)
+        })
+    }
+
+    /// Returns the number of indices available for reuse.
+    pub fn free_list_len(&self) -> usize {
+        self.cache.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns the capacity of the list of reusable indices.
+    pub fn free_list_capacity(&self) -> usize {
+        self.cache.cache.capacity()
+    }
+
+    /// Returns the generation of every index, `None` for indices which were
+    /// never used.
+    pub fn generations_slice(&self) -> &[Option<Generation>] {
+        let generations = &self.generations[..];
+        // SAFETY: `ZeroableGeneration` is `repr(transparent)` over
+        // `Option<Generation>`, so both slices have the same layout.
+        unsafe {
+            std::slice::from_raw_parts(
+                generations.as_ptr().cast::<Option<Generation>>(),
+                generations.len(),
+            )
+        }
+    }
+
+    /// Returns the indices available for reuse, sorted.
+    pub fn reservation_list(&self) -> Vec<Index> {
+        let len = self.cache.len.load(Ordering::Relaxed);
+        let mut list = self.cache.cache[..len].to_vec();
+        list.sort_unstable();
+        list
+    }
+
+    /// Checks the internal invariants of the allocator, returning the first
+    /// violation found.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        use hibitset::BitSetLike;
+
+        let max_id = self.max_id.load(Ordering::Relaxed);
+        let in_bounds = |id: Index| {
+            if (id as usize) < max_id {
+                Ok(())
+            } else {
+                Err(ValidationError::OutOfBounds(id))
+            }
+        };
+
+        for id in (&self.alive).iter() {
+            in_bounds(id)?;
+            if !self.generations[id as usize].is_alive() {
+                return Err(ValidationError::DeadGeneration(id));
+            }
+            if self.raised.contains(id) {
+                return Err(ValidationError::AliveAndRaised(id));
+            }
+        }
+        for id in (&self.raised).iter() {
+            in_bounds(id)?;
+        }
+        for id in (&self.killed).iter() {
+            if !self.alive.contains(id) && !self.raised.contains(id) {
+                return Err(ValidationError::KilledNotAllocated(id));
+            }
+        }
+
+        let cached = self.cache.len.load(Ordering::Relaxed);
+        for &id in &self.cache.cache[..cached] {
+            in_bounds(id)?;
+            if self.alive.contains(id) || self.raised.contains(id) {
+                return Err(ValidationError::CachedInUse(id));
+            }
+        }
+
+        Ok(())
+    }
+
     fn update_generation_length(&mut self, i: usize) {
         if self.generations.len() <= i {
             self.generations.resize(i + 1, ZeroableGeneration(None));
@@ -229,16 +390,79 @@ impl<'a> Iterator for CreateIterAtomic<'a> {
 }
 
 /// `Entity` type, as seen by the user.
-#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+///
+/// Note that entity handles are only meaningful for the `World` that created
+/// them; they should not be used as persistent keys (e.g. in save files)
+/// without going through `saveload`, since indices are reused.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Entity(Index, Generation);
 
+/// Creates an entity from an `(index, generation)` pair, e.g. as produced by
+/// `From<Entity> for (Index, i32)`. Positive generations are alive, negative
+/// ones dead. Fails if the generation is zero.
+impl TryFrom<(Index, i32)> for Entity {
+    type Error = ZeroGeneration;
+
+    fn try_from((index, gen): (Index, i32)) -> Result<Self, ZeroGeneration> {
+        NonZeroI32::new(gen)
+            .map(|gen| Entity(index, Generation(gen)))
+            .ok_or(ZeroGeneration)
+    }
+}
+
+/// Splits an entity into its index and generation id. The generation is
+/// positive if the entity was alive when it was created and negative if it
+/// refers to a dead generation.
+impl From<Entity> for (Index, i32) {
+    fn from(entity: Entity) -> Self {
+        (entity.id(), entity.gen().id())
+    }
+}
+
+/// Hashes the index and generation packed into a single `u64`, which is
+/// cheaper than hashing both fields separately.
+impl Hash for Entity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64((u64::from(self.1.id() as u32) << 32) | u64::from(self.0));
+    }
+}
+
 impl Entity {
     /// Creates a new entity (externally from ECS).
-    #[cfg(test)]
+    ///
+    /// This is useful for reconstructing entity handles, e.g. from network
+    /// packets. There is no guarantee that the entity is alive.
     pub fn new(index: Index, gen: Generation) -> Self {
         Self(index, gen)
     }
 
+    /// Returns an entity with the same index, but the given `Generation`.
+    #[inline]
+    pub fn with_gen(self, gen: Generation) -> Self {
+        Self(self.0, gen)
+    }
+
+    /// Returns the null entity, a sentinel meaning "no entity".
+    ///
+    /// It has the maximum `Index`, which is never handed out by the
+    /// allocator, and a dead `Generation`, so it is never alive. Note that
+    /// `Option<Entity>` has the same size as `Entity`, so prefer that where
+    /// it reads better.
+    #[inline]
+    pub fn null() -> Self {
+        // SAFETY: `-1` is not zero.
+        Self(
+            Index::MAX,
+            Generation(unsafe { NonZeroI32::new_unchecked(-1) }),
+        )
+    }
+
+    /// Returns `true` if this is the null entity.
+    #[inline]
+    pub fn is_null(self) -> bool {
+        self.0 == Index::MAX
+    }
+
     /// Returns the index of the `Entity`.
     #[inline]
     pub fn id(self) -> Index {
@@ -262,6 +486,10 @@ impl Entity {
 ///
 /// You need to call `World::maintain` after creating / deleting
 /// entities with this struct.
+///
+/// `EntitiesRes` is `Send` and `Sync`. Creating and deleting entities
+/// through a shared reference only touches atomic state, so it can be done
+/// from multiple threads at once.
 #[derive(Debug, Default)]
 pub struct EntitiesRes {
     pub(crate) alloc: Allocator,
@@ -282,6 +510,15 @@ impl EntitiesRes {
         self.alloc.allocate_atomic()
     }
 
+    /// Creates a new entity which is alive immediately, without waiting
+    /// for the next `World::maintain`.
+    ///
+    /// This requires mutable access, see `World::entities_mut`; inside
+    /// systems, use `create` instead.
+    pub fn allocate(&mut self) -> Entity {
+        self.alloc.allocate()
+    }
+
     /// Returns an iterator which creates
     /// new entities atomically.
     /// They will be persistent as soon
@@ -310,6 +547,22 @@ impl EntitiesRes {
         self.alloc.kill_atomic(e)
     }
 
+    /// Deletes all entities for which `f` returns `true` atomically.
+    /// The associated components will be
+    /// deleted as soon as you call `World::maintain`.
+    pub fn kill_if<F>(&self, mut f: F)
+    where
+        F: FnMut(Entity) -> bool,
+    {
+        for entity in self.join() {
+            if f(entity) {
+                self.alloc
+                    .kill_atomic(entity)
+                    .expect("Bug: joined entity is not alive");
+            }
+        }
+    }
+
     /// Returns an entity with a given `id`. There's no guarantee for validity,
     /// meaning the entity could be not alive.
     pub fn entity(&self, id: Index) -> Entity {
@@ -321,6 +574,57 @@ impl EntitiesRes {
     pub fn is_alive(&self, e: Entity) -> bool {
         self.alloc.is_alive(e)
     }
+
+    /// Returns the high-water mark of allocated indices. All entities, alive
+    /// or not, have an index smaller than this, so it can be used to size
+    /// buffers indexed by `Entity::id`.
+    pub fn max_allocated_id(&self) -> Index {
+        self.alloc.max_allocated_id()
+    }
+
+    /// Iterates over all entities that are alive as of the last
+    /// `World::maintain`, in order of their index.
+    ///
+    /// Unlike joining over `EntitiesRes`, this doesn't include entities
+    /// created atomically since then.
+    pub fn iter_alive(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.alloc.iter_alive()
+    }
+
+    /// Returns the number of indices of deleted entities which are available
+    /// for reuse by new entities.
+    pub fn free_list_len(&self) -> usize {
+        self.alloc.free_list_len()
+    }
+
+    /// Returns the capacity of the list of reusable indices, which may be
+    /// much larger than `free_list_len` after many entities were recycled.
+    pub fn free_list_capacity(&self) -> usize {
+        self.alloc.free_list_capacity()
+    }
+
+    /// Returns the sorted indices of deleted entities which are available for
+    /// reuse by new entities.
+    pub fn reservation_list(&self) -> Vec<Index> {
+        self.alloc.reservation_list()
+    }
+
+    /// Returns the generation of every index, indexed by `Entity::id`.
+    /// Indices which were never used are `None`, and indices of deleted
+    /// entities have a dead generation.
+    ///
+    /// This doesn't include entities created atomically since the last
+    /// `maintain`.
+    pub fn generations_slice(&self) -> &[Option<Generation>] {
+        self.alloc.generations_slice()
+    }
+
+    /// Checks the invariants of the entity allocator, returning a
+    /// `ValidationError` describing the first violation. This is a
+    /// diagnostic tool; a violation indicates a bug in Specs.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.alloc.validate()
+    }
 }
 
 // SAFETY: It is safe to retrieve elements with any `id` regardless of the mask.
@@ -368,6 +672,31 @@ unsafe impl<'a> Join for &'a EntitiesRes {
     }
 }
 
+/// Iterates over all live entities, including the ones which were created
+/// atomically but not merged with `World::maintain` yet.
+///
+/// ```
+/// use specs::prelude::*;
+///
+/// let mut world = World::new();
+/// world.create_entity().build();
+/// world.entities().create();
+///
+/// let mut count = 0;
+/// for _entity in &*world.entities() {
+///     count += 1;
+/// }
+/// assert_eq!(count, 2);
+/// ```
+impl<'a> IntoIterator for &'a EntitiesRes {
+    type IntoIter = JoinIter<Self>;
+    type Item = Entity;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.join()
+    }
+}
+
 // SAFETY: No unsafe code is used and it is safe to call `get` from multiple
 // threads at once.
 //
@@ -406,11 +735,26 @@ pub struct EntityResBuilder<'a> {
 
 impl<'a> EntityResBuilder<'a> {
     /// Appends a component and associates it with the entity.
+    ///
+    /// If the entity already has a component of this type, it is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity is no longer alive.
     pub fn with<T: Component>(self, c: T, storage: &mut WriteStorage<T>) -> Self {
         storage.insert(self.entity, c).unwrap();
         self
     }
 
+    /// Removes a component from the entity, if it has one.
+    ///
+    /// This is useful when the components were copied from a template and
+    /// some of them should not end up on the built entity.
+    pub fn without<T: Component>(self, storage: &mut WriteStorage<T>) -> Self {
+        storage.remove(self.entity);
+        self
+    }
+
     /// Finishes the building and returns the entity.
     pub fn build(mut self) -> Entity {
         self.built = true;
@@ -440,12 +784,24 @@ impl fmt::Debug for Generation {
 }
 
 impl Generation {
+    /// The largest (alive) generation.
+    // SAFETY: `i32::MAX` is not zero.
+    pub const MAX: Generation = Generation(unsafe { NonZeroI32::new_unchecked(i32::MAX) });
+    /// The smallest (dead) generation. `i32::MIN` itself is never used since
+    /// it has no alive counterpart.
+    // SAFETY: `i32::MIN + 1` is not zero.
+    pub const MIN: Generation = Generation(unsafe { NonZeroI32::new_unchecked(i32::MIN + 1) });
+
     pub(crate) fn one() -> Self {
         // SAFETY: `1` is not zero.
         Generation(unsafe { NonZeroI32::new_unchecked(1) })
     }
 
-    #[cfg(test)]
+    /// Creates a `Generation` from its id; negative ids are dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is zero.
     pub fn new(v: i32) -> Self {
         Generation(NonZeroI32::new(v).expect("generation id must be non-zero"))
     }
@@ -462,6 +818,18 @@ impl Generation {
         self.id() > 0
     }
 
+    /// Returns how many times an index was recycled between this `Generation`
+    /// and `other`, i.e. the number of create / delete cycles that separate
+    /// them.
+    ///
+    /// A generation and the dead generation it turns into when its entity is
+    /// deleted have a distance of `0`. Only meaningful for generations of the
+    /// same index.
+    #[inline]
+    pub fn distance(self, other: Generation) -> u32 {
+        self.id().unsigned_abs().abs_diff(other.id().unsigned_abs())
+    }
+
     /// Revives and increments a dead `Generation`.
     ///
     /// # Panics
@@ -479,6 +847,7 @@ impl Generation {
 
 /// Convenience wrapper around Option<Generation>
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[repr(transparent)]
 struct ZeroableGeneration(Option<Generation>);
 
 impl ZeroableGeneration {
@@ -548,6 +917,12 @@ impl EntityCache {
         x
     }
 
+    fn remove(&mut self, id: Index) {
+        self.maintain();
+        self.cache.retain(|&cached| cached != id);
+        *self.len.get_mut() = self.cache.len();
+    }
+
     fn maintain(&mut self) {
         self.cache.truncate(*(self.len.get_mut()));
     }
@@ -594,6 +969,16 @@ fn atomic_decrement(i: &AtomicUsize) -> Option<usize> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn thread_safety() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Entity>();
+        assert_send_sync::<Generation>();
+        assert_send_sync::<EntitiesRes>();
+        assert_send_sync::<EntityResBuilder<'static>>();
+    }
+
     #[test]
     fn test_nonzero_optimization() {
         use std::mem::size_of;
@@ -601,6 +986,236 @@ mod tests {
         assert_eq!(size_of::<Option<Entity>>(), size_of::<Entity>());
     }
 
+    #[test]
+    fn generation_distance() {
+        let mut gen = ZeroableGeneration(None);
+        let first = gen.raise();
+        assert_eq!(first.distance(first), 0);
+
+        gen.die();
+        let dead = gen.0.unwrap();
+        assert_eq!(first.distance(dead), 0);
+
+        let second = gen.raise();
+        assert_eq!(first.distance(second), 1);
+        assert_eq!(dead.distance(second), 1);
+
+        gen.die();
+        let third = gen.raise();
+        assert_eq!(first.distance(third), 2);
+        assert_eq!(third.distance(first), 2);
+        assert_eq!(Generation::new(-5).distance(Generation::new(9)), 4);
+    }
+
+    #[test]
+    fn null_entity() {
+        let mut allocator = Allocator::default();
+        let e = allocator.allocate();
+        allocator.allocate_atomic();
+
+        assert!(Entity::null().is_null());
+        assert!(!e.is_null());
+        assert!(!allocator.is_alive(Entity::null()));
+        allocator.merge_with(|_| {});
+        assert!(!allocator.is_alive(Entity::null()));
+    }
+
+    #[test]
+    fn validate() {
+        let mut allocator = Allocator::default();
+        assert_eq!(allocator.validate(), Ok(()));
+
+        let a = allocator.allocate();
+        let b = allocator.allocate();
+        let c = allocator.allocate_atomic();
+        allocator.kill_atomic(a).unwrap();
+        allocator.kill_atomic(c).unwrap();
+        assert_eq!(allocator.validate(), Ok(()));
+
+        allocator.kill(&[b]).unwrap();
+        assert_eq!(allocator.validate(), Ok(()));
+        allocator.merge_with(|_| {});
+        assert_eq!(allocator.validate(), Ok(()));
+        allocator.allocate_atomic();
+        let d = allocator.allocate();
+        assert_eq!(allocator.validate(), Ok(()));
+
+        allocator.generations[d.id() as usize].die();
+        assert_eq!(
+            allocator.validate(),
+            Err(ValidationError::DeadGeneration(d.id()))
+        );
+    }
+
+    #[test]
+    fn entity_with_gen() {
+        let mut allocator = Allocator::default();
+        let e = allocator.allocate();
+        allocator.kill(&[e]).unwrap();
+        let revived = allocator.allocate();
+
+        assert_eq!(revived.id(), e.id());
+        assert_eq!(Entity::new(e.id(), revived.gen()), revived);
+        assert_eq!(e.with_gen(revived.gen()), revived);
+        assert!(allocator.is_alive(e.with_gen(Generation::new(2))));
+        assert!(!allocator.is_alive(revived.with_gen(Generation::new(1))));
+    }
+
+    #[test]
+    fn allocate_at() {
+        let mut allocator = Allocator::default();
+        let a = allocator.allocate();
+
+        let c = allocator.allocate_at(3).unwrap();
+        assert_eq!(c.id(), 3);
+        assert!(allocator.is_alive(c));
+        assert_eq!(allocator.allocate_at(3), None);
+        assert_eq!(allocator.allocate_at(a.id()), None);
+        assert_eq!(allocator.validate(), Ok(()));
+
+        let b = allocator.allocate_at(2).unwrap();
+        assert_eq!(b.id(), 2);
+        // index 1 was skipped over and is reused
+        assert_eq!(allocator.allocate().id(), 1);
+        assert_eq!(allocator.allocate().id(), 4);
+
+        allocator.kill(&[c]).unwrap();
+        let revived = allocator.allocate_at(3).unwrap();
+        assert_eq!(revived, c.with_gen(Generation::new(2)));
+        assert_eq!(allocator.allocate().id(), 5);
+        assert_eq!(allocator.validate(), Ok(()));
+    }
+
+    #[test]
+    fn allocate_at_out_of_range() {
+        let mut allocator = Allocator::default();
+        allocator.allocate();
+
+        assert_eq!(allocator.allocate_at(MAX_INDICES as Index), None);
+        assert_eq!(allocator.allocate_at(Index::MAX), None);
+        assert_eq!(allocator.max_allocated_id(), 1);
+        assert_eq!(allocator.validate(), Ok(()));
+        assert_eq!(allocator.allocate().id(), 1);
+    }
+
+    #[test]
+    fn free_list_len() {
+        let mut allocator = Allocator::default();
+        let entities: Vec<_> = (0..4).map(|_| allocator.allocate()).collect();
+        assert_eq!(allocator.free_list_len(), 0);
+
+        allocator.kill(&entities[..3]).unwrap();
+        assert_eq!(allocator.free_list_len(), 3);
+        assert!(allocator.free_list_capacity() >= 3);
+
+        allocator.allocate_atomic();
+        assert_eq!(allocator.free_list_len(), 2);
+        allocator.allocate();
+        assert_eq!(allocator.free_list_len(), 1);
+    }
+
+    #[test]
+    fn reservation_list() {
+        let mut allocator = Allocator::default();
+        let entities: Vec<_> = (0..5).map(|_| allocator.allocate()).collect();
+        assert!(allocator.reservation_list().is_empty());
+
+        allocator
+            .kill(&[entities[3], entities[0], entities[4]])
+            .unwrap();
+        assert_eq!(allocator.reservation_list(), vec![0, 3, 4]);
+
+        let reused = allocator.allocate_atomic();
+        let mut expected = vec![0, 3, 4];
+        expected.retain(|&id| id != reused.id());
+        assert_eq!(allocator.reservation_list(), expected);
+    }
+
+    #[test]
+    fn generations_slice() {
+        let mut allocator = Allocator::default();
+        assert!(allocator.generations_slice().is_empty());
+
+        let entities: Vec<_> = (0..3).map(|_| allocator.allocate()).collect();
+        allocator.kill(&[entities[1]]).unwrap();
+
+        let alive: Vec<_> = allocator
+            .generations_slice()
+            .iter()
+            .map(|gen| gen.map(|gen| gen.is_alive()))
+            .collect();
+        assert_eq!(alive, vec![Some(true), Some(false), Some(true)]);
+        assert_eq!(allocator.generations_slice()[0], Some(entities[0].gen()));
+    }
+
+    #[test]
+    fn generation_bounds() {
+        assert!(Generation::MAX.is_alive());
+        assert!(!Generation::MIN.is_alive());
+        assert_eq!(Generation::MIN.id(), -Generation::MAX.id());
+        assert!(Generation::MIN < Generation::one() && Generation::one() < Generation::MAX);
+    }
+
+    #[test]
+    fn iter_alive() {
+        let mut allocator = Allocator::default();
+        let a = allocator.allocate();
+        let b = allocator.allocate();
+        let c = allocator.allocate();
+        allocator.kill(&[b]).unwrap();
+        let atomic = allocator.allocate_atomic();
+
+        assert_eq!(allocator.iter_alive().collect::<Vec<_>>(), vec![a, c]);
+        allocator.merge_with(|_| {});
+        assert_eq!(
+            allocator.iter_alive().collect::<Vec<_>>(),
+            vec![a, atomic, c]
+        );
+    }
+
+    #[test]
+    fn entity_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(e: Entity) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            e.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = Entity::new(3, Generation::new(1));
+        assert_eq!(hash(a), hash(Entity::new(3, Generation::new(1))));
+        assert_ne!(hash(a), hash(Entity::new(3, Generation::new(2))));
+        assert_ne!(hash(a), hash(Entity::new(4, Generation::new(1))));
+        assert_ne!(hash(a), hash(Entity::new(3, Generation::new(-1))));
+    }
+
+    #[test]
+    fn entity_tuple_conversion() {
+        let e = Entity::new(7, Generation::new(3));
+        let pair: (Index, i32) = e.into();
+
+        assert_eq!(pair, (7, 3));
+        assert_eq!(Entity::try_from(pair), Ok(e));
+        assert!(!Entity::try_from((7, -3)).unwrap().gen().is_alive());
+        assert_eq!(Entity::try_from((7, 0)), Err(ZeroGeneration));
+    }
+
+    #[test]
+    fn max_allocated_id() {
+        let mut allocator = Allocator::default();
+        assert_eq!(allocator.max_allocated_id(), 0);
+
+        let a = allocator.allocate();
+        allocator.allocate_atomic();
+        assert_eq!(allocator.max_allocated_id(), 2);
+
+        allocator.kill(&[a]).unwrap();
+        allocator.merge_with(|_| {});
+        allocator.allocate();
+        assert_eq!(allocator.max_allocated_id(), 2);
+    }
+
     #[test]
     fn kill_atomic_create_merge() {
         let mut allocator = Allocator::default();
@@ -613,7 +1228,7 @@ mod tests {
         assert_ne!(allocator.allocate(), entity);
 
         assert_eq!(allocator.killed.contains(entity.id()), true);
-        assert_eq!(allocator.merge(), vec![entity]);
+        assert_eq!(allocator.merge_with(|_| {}), vec![entity]);
     }
 
     #[test]
@@ -631,6 +1246,6 @@ mod tests {
         allocator.allocate();
 
         assert_eq!(allocator.killed.contains(entity.id()), false);
-        assert_eq!(allocator.merge(), vec![]);
+        assert_eq!(allocator.merge_with(|_| {}), vec![]);
     }
 }
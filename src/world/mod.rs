@@ -8,7 +8,8 @@ pub use self::{
         CreateIterAtomic, Entities, EntitiesRes, Entity, EntityResBuilder, Generation, Index,
     },
     lazy::{LazyBuilder, LazyUpdate},
-    world_ext::WorldExt,
+    registry::TypeRegistry,
+    world_ext::{DebugWorld, WorldExt},
 };
 
 use shred::{FetchMut, SystemData};
@@ -18,6 +19,7 @@ use crate::storage::WriteStorage;
 mod comp;
 mod entity;
 mod lazy;
+mod registry;
 #[cfg(test)]
 mod tests;
 mod world_ext;
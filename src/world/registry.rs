@@ -0,0 +1,82 @@
+use std::any::{type_name, TypeId};
+
+use ahash::AHashMap as HashMap;
+
+/// A resource mapping the `TypeId`s of registered components to their type
+/// names.
+///
+/// It is inserted by `WorldExt::new` and every component registered with the
+/// world, either through `WorldExt::register` or by setting up a system that
+/// uses its storage, is added to it. This allows turning the otherwise opaque
+/// `TypeId`s into human-readable names.
+///
+/// ## Examples
+///
+/// ```
+/// use specs::{prelude::*, world::TypeRegistry};
+/// use std::any::TypeId;
+///
+/// struct Pos;
+///
+/// impl Component for Pos {
+///     type Storage = VecStorage<Self>;
+/// }
+///
+/// let mut world = World::new();
+/// world.register::<Pos>();
+///
+/// let registry = world.read_resource::<TypeRegistry>();
+/// assert!(registry
+///     .name_of(TypeId::of::<Pos>())
+///     .unwrap()
+///     .ends_with("Pos"));
+/// ```
+#[derive(Debug, Default)]
+pub struct TypeRegistry {
+    names: HashMap<TypeId, &'static str>,
+}
+
+impl TypeRegistry {
+    /// Creates a new, empty registry.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the type `T` to the registry.
+    pub fn register<T: 'static>(&mut self) {
+        self.names.insert(TypeId::of::<T>(), type_name::<T>());
+    }
+
+    /// Removes the type `T` from the registry.
+    pub fn unregister<T: 'static>(&mut self) {
+        self.names.remove(&TypeId::of::<T>());
+    }
+
+    /// Returns the name of the type with the given `TypeId`, if it has been
+    /// registered.
+    pub fn name_of(&self, type_id: TypeId) -> Option<&'static str> {
+        self.names.get(&type_id).copied()
+    }
+
+    /// Returns `true` if the type with the given `TypeId` has been
+    /// registered.
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.names.contains_key(&type_id)
+    }
+
+    /// Returns the number of registered types.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no type has been registered.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterates over all registered types and their names, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (TypeId, &'static str)> + '_ {
+        self.names.iter().map(|(&id, &name)| (id, name))
+    }
+}
@@ -13,6 +13,263 @@ impl Component for Vel {
     type Storage = VecStorage<Self>;
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct Health(i32);
+
+impl Component for Health {
+    type Storage = VecStorage<Self>;
+}
+
+/// Creates a world with one entity per value, returning the world and the
+/// created entities.
+fn health_world(values: &[i32]) -> (World, Vec<Entity>) {
+    let mut world = World::new();
+    world.register::<Health>();
+    let entities = values
+        .iter()
+        .map(|&value| world.create_entity().with(Health(value)).build())
+        .collect();
+    (world, entities)
+}
+
+#[test]
+fn assert_components_eq() {
+    let (left, _) = health_world(&[1, 2, 3]);
+    let (right, _) = health_world(&[1, 2, 3]);
+    left.assert_components_eq::<Health>(&right);
+}
+
+#[test]
+#[should_panic(expected = "Health(2) != Health(5)")]
+fn assert_components_eq_differ() {
+    let (left, _) = health_world(&[1, 2, 3]);
+    let (right, _) = health_world(&[1, 5, 3]);
+    left.assert_components_eq::<Health>(&right);
+}
+
+#[test]
+#[should_panic(expected = "is missing on the left")]
+fn assert_components_eq_missing() {
+    let (left, _) = health_world(&[1, 2]);
+    let (right, _) = health_world(&[1, 2, 3]);
+    left.assert_components_eq::<Health>(&right);
+}
+
+#[test]
+#[should_panic(expected = "is only alive on the right")]
+fn assert_components_eq_entities() {
+    let (left, _) = health_world(&[1, 2]);
+    let (mut right, _) = health_world(&[1, 2]);
+    right.create_entity().build();
+    left.assert_components_eq::<Health>(&right);
+}
+
+#[test]
+fn with_component() {
+    let (mut world, entities) = health_world(&[1]);
+    let e = entities[0];
+    let empty = world.create_entity().build();
+
+    assert_eq!(world.with_component(e, |h: &Health| h.0), Some(1));
+    assert_eq!(world.with_component(empty, |h: &Health| h.0), None);
+
+    world.with_component_mut(e, |h: &mut Health| h.0 = 7);
+    assert_eq!(
+        world.with_component_mut(empty, |h: &mut Health| h.0 = 7),
+        None
+    );
+    assert_eq!(world.read_storage::<Health>().get(e), Some(&Health(7)));
+}
+
+#[test]
+fn remove_component() {
+    let (world, entities) = health_world(&[4]);
+    let e = entities[0];
+
+    assert_eq!(world.remove_component::<Health>(e), Some(Health(4)));
+    assert_eq!(world.remove_component::<Health>(e), None);
+}
+
+#[test]
+fn clear_component() {
+    let (world, _) = health_world(&[1, 2, 3]);
+    world.clear_component::<Health>();
+
+    assert_eq!(world.read_storage::<Health>().count(), 0);
+    assert_eq!(world.entities().join().count(), 3);
+}
+
+#[test]
+fn swap_components() {
+    let (mut world, entities) = health_world(&[1, 2]);
+    let (a, b) = (entities[0], entities[1]);
+    let empty = world.create_entity().build();
+
+    world.swap_components::<Health>(a, b).unwrap();
+    assert_eq!(world.read_storage::<Health>().get(a), Some(&Health(2)));
+    assert_eq!(world.read_storage::<Health>().get(b), Some(&Health(1)));
+
+    world.swap_components::<Health>(a, a).unwrap();
+    assert_eq!(world.read_storage::<Health>().get(a), Some(&Health(2)));
+
+    world.swap_components::<Health>(a, empty).unwrap();
+    assert!(!world.has_component::<Health>(a));
+    assert_eq!(world.read_storage::<Health>().get(empty), Some(&Health(2)));
+
+    world.delete_entity(b).unwrap();
+    assert!(world.swap_components::<Health>(a, b).is_err());
+}
+
+#[test]
+fn has_component() {
+    let (mut world, entities) = health_world(&[4]);
+    let e = entities[0];
+    let empty = world.create_entity().build();
+
+    assert!(world.has_component::<Health>(e));
+    assert!(!world.has_component::<Health>(empty));
+
+    world.delete_entity(e).unwrap();
+    assert!(!world.has_component::<Health>(e));
+}
+
+#[test]
+fn create_entity_batch() {
+    let mut world = World::new();
+    let entities = world.create_entity_batch(10);
+
+    assert_eq!(entities.len(), 10);
+    assert!(entities.iter().all(|&e| world.is_alive(e)));
+    assert_eq!(world.maintain_dry_run(), (vec![], vec![]));
+}
+
+#[test]
+fn migrate_component() {
+    #[derive(Debug, PartialEq)]
+    struct HealthV2(i64);
+
+    impl Component for HealthV2 {
+        type Storage = VecStorage<Self>;
+    }
+
+    let (mut world, _) = health_world(&[1, 2]);
+    world.register::<HealthV2>();
+    let empty = world.create_entity().build();
+
+    world.migrate_component(|Health(h): Health| HealthV2(i64::from(h) * 10));
+
+    assert_eq!(world.read_storage::<Health>().count(), 0);
+    let migrated = world.read_storage::<HealthV2>();
+    let values: Vec<_> = migrated.iter().map(|(_, h)| h.0).collect();
+    assert_eq!(values, vec![10, 20]);
+    assert!(!migrated.contains(empty));
+}
+
+#[test]
+fn migrate_component_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[derive(Debug, PartialEq)]
+    struct HealthV2(i32);
+
+    impl Component for HealthV2 {
+        type Storage = VecStorage<Self>;
+    }
+
+    let (mut world, entities) = health_world(&[1, 2, 3]);
+    world.register::<HealthV2>();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        world.migrate_component(|Health(h): Health| {
+            assert_ne!(h, 2, "can't migrate");
+            HealthV2(h)
+        })
+    }));
+    assert!(result.is_err());
+
+    let old = world.read_storage::<Health>();
+    let new = world.read_storage::<HealthV2>();
+    assert_eq!(new.get(entities[0]), Some(&HealthV2(1)));
+    assert!(!old.contains(entities[1]) && !new.contains(entities[1]));
+    assert_eq!(old.get(entities[2]), Some(&Health(3)));
+}
+
+#[test]
+fn kill_if() {
+    let mut world = World::new();
+    let entities = world.create_entity_batch(6);
+
+    world.entities().kill_if(|e| e.id() % 2 == 0);
+    world.maintain();
+
+    for e in entities {
+        assert_eq!(world.is_alive(e), e.id() % 2 == 1);
+    }
+}
+
+#[test]
+fn component_dyn() {
+    use std::any::{Any, TypeId};
+
+    let (mut world, entities) = health_world(&[3]);
+    world.register::<Pos>();
+    let e = entities[0];
+    let health = TypeId::of::<Health>();
+
+    let value = world.with_component_dyn(e, health, |c: &dyn Any| {
+        c.downcast_ref::<Health>().map(|h| h.0)
+    });
+    assert_eq!(value, Some(Some(3)));
+    assert_eq!(
+        world.with_component_dyn(e, TypeId::of::<Pos>(), |_| ()),
+        None
+    );
+    assert_eq!(
+        world.with_component_dyn(e, TypeId::of::<u8>(), |_| ()),
+        None
+    );
+
+    let ptr = unsafe { world.get_component_raw(e, health) }.unwrap();
+    assert_eq!(unsafe { &*(ptr as *const Health) }, &Health(3));
+
+    world.delete_entity(e).unwrap();
+    assert!(unsafe { world.get_component_raw(e, health) }.is_none());
+}
+
+#[test]
+fn deregister() {
+    use std::any::TypeId;
+
+    let (mut world, entities) = health_world(&[1, 2]);
+    world.register::<Pos>();
+    let e = entities[0];
+
+    assert!(world.deregister::<Health>());
+    assert!(!world.deregister::<Health>());
+    assert!(!world.has_value::<crate::storage::MaskedStorage<Health>>());
+    assert_eq!(world.component_type_ids(), vec![TypeId::of::<Pos>()]);
+    assert_eq!(world.component_names(), vec![std::any::type_name::<Pos>()]);
+
+    // deleting entities still works without the storage
+    world.delete_entity(e).unwrap();
+    world.maintain();
+
+    world.register::<Health>();
+    assert_eq!(world.read_storage::<Health>().count(), 0);
+    assert_eq!(world.component_type_ids().len(), 2);
+}
+
+#[test]
+fn component_data() {
+    let (mut world, entities) = health_world(&[5, 6, 7]);
+    world.delete_entity(entities[1]).unwrap();
+
+    assert_eq!(
+        world.component_data::<Health>(),
+        vec![(entities[0], Health(5)), (entities[2], Health(7))]
+    );
+}
+
 #[test]
 fn delete_all() {
     let mut world = World::new();
@@ -32,6 +289,396 @@ fn delete_all() {
     assert!(world.read_storage::<Pos>().get(b).is_none());
 }
 
+#[test]
+fn component_count() {
+    let mut world = World::new();
+    world.register::<Pos>();
+    world.register::<Vel>();
+
+    assert_eq!(world.component_count::<Pos>(), 0);
+
+    let a = world.create_entity().with(Pos).build();
+    world.create_entity().with(Pos).with(Vel).build();
+    world.create_entity().build();
+
+    assert_eq!(world.component_count::<Pos>(), 2);
+    assert_eq!(world.component_count::<Vel>(), 1);
+
+    world.delete_entity(a).unwrap();
+    assert_eq!(world.component_count::<Pos>(), 1);
+}
+
+#[test]
+fn debug_world() {
+    let mut world = World::new();
+    world.register::<Pos>();
+    world.register::<Vel>();
+
+    world.create_entity().with(Pos).with(Vel).build();
+    world.create_entity().with(Pos).build();
+
+    let debug = format!("{:?}", world.debug());
+    assert!(debug.starts_with("World { entities: 2, components: {"));
+    assert!(debug.contains("::Pos\": 2"));
+    assert!(debug.contains("::Vel\": 1"));
+}
+
+#[test]
+fn component_type_ids() {
+    use std::any::TypeId;
+
+    let mut world = World::new();
+    assert!(world.component_type_ids().is_empty());
+
+    world.register::<Pos>();
+    world.register::<Vel>();
+    world.register::<Pos>();
+
+    assert_eq!(
+        world.component_type_ids(),
+        vec![TypeId::of::<Pos>(), TypeId::of::<Vel>()]
+    );
+}
+
+#[test]
+fn component_storage_count() {
+    let mut world = World::new();
+    assert_eq!(world.component_storage_count(), 0);
+
+    world.register::<Pos>();
+    world.register::<Vel>();
+    world.register::<Pos>();
+    assert_eq!(world.component_storage_count(), 2);
+
+    world.deregister::<Vel>();
+    assert_eq!(world.component_storage_count(), 1);
+}
+
+#[test]
+fn type_registry() {
+    use std::any::TypeId;
+
+    let mut world = World::new();
+    assert!(world.read_resource::<TypeRegistry>().is_empty());
+
+    world.register::<Pos>();
+
+    let registry = world.read_resource::<TypeRegistry>();
+    assert_eq!(registry.len(), 1);
+    assert_eq!(
+        registry.name_of(TypeId::of::<Pos>()),
+        Some(std::any::type_name::<Pos>())
+    );
+    assert_eq!(registry.name_of(TypeId::of::<Vel>()), None);
+    drop(registry);
+
+    <crate::storage::ReadStorage<Vel> as shred::SystemData>::setup(&mut world);
+    assert!(world
+        .read_resource::<TypeRegistry>()
+        .contains(TypeId::of::<Vel>()));
+}
+
+#[test]
+fn move_component() {
+    let mut world = World::new();
+    world.register::<Pos>();
+
+    let a = world.create_entity().with(Pos).build();
+    let b = world.create_entity().build();
+    let c = world.create_entity().with(Pos).build();
+
+    assert!(world.move_component::<Pos>(a, b).unwrap());
+    assert!(!world.read_storage::<Pos>().contains(a));
+    assert!(world.read_storage::<Pos>().contains(b));
+
+    // `a` has nothing left to move
+    let d = world.create_entity().build();
+    assert!(!world.move_component::<Pos>(a, d).unwrap());
+    assert!(!world.read_storage::<Pos>().contains(d));
+
+    // `c` is already occupied
+    match world.move_component::<Pos>(b, c) {
+        Err(crate::error::Error::ComponentExists(e)) => assert_eq!(e.entity, c),
+        other => panic!("expected `ComponentExists`, got {:?}", other),
+    }
+    assert!(world.read_storage::<Pos>().contains(b));
+
+    world.delete_entity(a).unwrap();
+    assert!(matches!(
+        world.move_component::<Pos>(b, a),
+        Err(crate::error::Error::WrongGeneration(_))
+    ));
+    assert!(world.read_storage::<Pos>().contains(b));
+}
+
+#[test]
+fn transfer_entity() {
+    let mut source = World::new();
+    source.register::<Pos>();
+    source.register::<Vel>();
+    let mut target = World::new();
+    target.register::<Pos>();
+
+    let e = source.create_entity().with(Pos).with(Vel).build();
+    let moved = source.transfer_entity(e, &mut target).unwrap();
+
+    assert!(!source.is_alive(e));
+    assert_eq!(source.read_storage::<Pos>().count(), 0);
+    assert_eq!(source.read_storage::<Vel>().count(), 0);
+    assert!(target.is_alive(moved));
+    assert!(target.read_storage::<Pos>().contains(moved));
+
+    assert!(source.transfer_entity(e, &mut target).is_err());
+    assert_eq!(target.entities().join().count(), 1);
+}
+
+#[test]
+fn with_resource() {
+    let mut world = World::new();
+    world.insert(5u32);
+
+    world.with_resource(|r: &mut u32| *r += 1);
+    assert_eq!(*world.read_resource::<u32>(), 6);
+    assert_eq!(world.with_resource(|r: &mut u32| *r * 2), 12);
+
+    world.with_or_default(|r: &mut u64| *r += 3);
+    world.with_or_default(|r: &mut u64| *r += 3);
+    assert_eq!(*world.read_resource::<u64>(), 6);
+}
+
+#[test]
+fn maintain_dry_run() {
+    let mut world = World::new();
+    let existing = world.create_entity().build();
+
+    let created = world.entities().create();
+    world.entities().delete(existing).unwrap();
+    assert_eq!(world.maintain_dry_run(), (vec![created], vec![existing]));
+    // nothing has been applied yet
+    assert_eq!(world.maintain_dry_run(), (vec![created], vec![existing]));
+
+    let mut reported = (vec![], vec![]);
+    world.maintain_with_hooks(|e| reported.0.push(e), |e| reported.1.push(e));
+    assert_eq!(reported, (vec![created], vec![existing]));
+    assert_eq!(world.maintain_dry_run(), (vec![], vec![]));
+}
+
+#[test]
+fn entities_mut_allocate() {
+    let world = World::new();
+    let e = world.entities_mut().allocate();
+
+    assert!(world.is_alive(e));
+    assert_eq!(world.maintain_dry_run(), (vec![], vec![]));
+}
+
+#[test]
+fn no_dangling_references() {
+    let mut world = World::new();
+    let a = world.create_entity().build();
+    let b = world.create_entity().build();
+
+    world.entities().delete(b).unwrap();
+    world.assert_no_dangling_references(&[a, b]);
+    world.maintain();
+    world.assert_no_dangling_references(&[a]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Dangling reference")]
+fn dangling_reference() {
+    let mut world = World::new();
+    let a = world.create_entity().build();
+
+    world.delete_entity(a).unwrap();
+    world.assert_no_dangling_references(&[a]);
+}
+
+#[test]
+fn component_names() {
+    let mut world = World::new();
+    assert!(world.component_names().is_empty());
+
+    world.register::<Vel>();
+    world.register::<Pos>();
+
+    assert_eq!(
+        world.component_names(),
+        vec![std::any::type_name::<Pos>(), std::any::type_name::<Vel>()]
+    );
+}
+
+#[test]
+fn component_names_from_dispatcher_setup() {
+    use std::any::TypeId;
+
+    struct Move;
+
+    impl<'a> shred::System<'a> for Move {
+        type SystemData = (crate::ReadStorage<'a, Vel>, crate::WriteStorage<'a, Pos>);
+
+        fn run(&mut self, _: Self::SystemData) {}
+    }
+
+    let mut world = World::new();
+    let mut dispatcher = crate::DispatcherBuilder::new()
+        .with(Move, "move", &[])
+        .build();
+    dispatcher.setup(&mut world);
+
+    assert_eq!(
+        world.component_names(),
+        vec![std::any::type_name::<Pos>(), std::any::type_name::<Vel>()]
+    );
+    let registry = world.read_resource::<TypeRegistry>();
+    assert!(registry.contains(TypeId::of::<Pos>()));
+    assert!(registry.contains(TypeId::of::<Vel>()));
+}
+
+#[test]
+fn resource_changed() {
+    let mut world = World::new();
+    world.insert(0u32);
+    world.insert(0u64);
+    world.maintain();
+    assert!(!world.resource_changed::<u32>());
+
+    *world.write_resource::<u32>() += 1;
+    assert!(!world.resource_changed::<u32>());
+
+    world.mark_resource_changed::<u32>();
+    assert!(world.resource_changed::<u32>());
+    assert!(!world.resource_changed::<u64>());
+
+    world.maintain();
+    assert!(!world.resource_changed::<u32>());
+
+    world.mark_resource_changed::<u64>();
+    assert!(world.resource_changed::<u64>());
+}
+
+#[test]
+fn entities_not_changed_by_maintain() {
+    let mut world = World::new();
+    let e = world.create_entity().build();
+    world.delete_entity(e).unwrap();
+    world.entities().create();
+    world.maintain();
+
+    assert!(!world.resource_changed::<EntitiesRes>());
+}
+
+#[test]
+fn resource_or_insert_with() {
+    let mut world = World::new();
+
+    *world.resource_or_insert_with(|| 1u32) += 1;
+    *world.resource_or_insert_with(|| 10u32) += 1;
+    assert_eq!(*world.read_resource::<u32>(), 3);
+}
+
+#[test]
+fn resource_or_default() {
+    let mut world = World::new();
+
+    world.resource_or_default::<Vec<u32>>().push(1);
+    world.resource_or_default::<Vec<u32>>().push(2);
+    assert_eq!(*world.read_resource::<Vec<u32>>(), vec![1, 2]);
+}
+
+#[test]
+fn copy_resource() {
+    let mut src = World::new();
+    src.insert(vec![1, 2, 3]);
+    let mut dst = World::new();
+    dst.insert(vec![4]);
+
+    dst.copy_resource::<Vec<i32>>(&src);
+    assert_eq!(*dst.read_resource::<Vec<i32>>(), vec![1, 2, 3]);
+
+    dst.write_resource::<Vec<i32>>().push(4);
+    assert_eq!(*src.read_resource::<Vec<i32>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn try_fetch_resource() {
+    struct Score(u32);
+
+    let mut world = World::new();
+    assert!(world.try_read_resource::<Score>().is_none());
+    assert!(world.try_write_resource::<Score>().is_none());
+
+    world.insert(Score(1));
+    world.try_write_resource::<Score>().unwrap().0 += 1;
+    assert_eq!(world.try_read_resource::<Score>().unwrap().0, 2);
+}
+
+#[test]
+fn entity_res_builder_without() {
+    let mut world = World::new();
+    world.register::<Pos>();
+    world.register::<Vel>();
+
+    let e = world.exec(
+        |(entities, mut pos, mut vel): (Entities, WriteStorage<Pos>, WriteStorage<Vel>)| {
+            entities
+                .build_entity()
+                .with(Pos, &mut pos)
+                .with(Vel, &mut vel)
+                .without(&mut pos)
+                .build()
+        },
+    );
+    world.maintain();
+
+    assert!(world.read_storage::<Pos>().get(e).is_none());
+    assert!(world.read_storage::<Vel>().get(e).is_some());
+}
+
+#[test]
+fn maintain_with_hooks() {
+    let mut world = World::new();
+    world.register::<Pos>();
+
+    let a = world.create_entity().with(Pos).build();
+    let (b, c) = {
+        let entities = world.entities();
+        entities.delete(a).unwrap();
+        (entities.create(), entities.create())
+    };
+
+    let mut created = vec![];
+    let mut deleted = vec![];
+    world.maintain_with_hooks(|e| created.push(e), |e| deleted.push(e));
+
+    created.sort();
+    assert_eq!(created, vec![b, c]);
+    assert_eq!(deleted, vec![a]);
+    assert!(world.read_storage::<Pos>().get(a).is_none());
+
+    world.maintain_with_hooks(
+        |_| panic!("no entity created"),
+        |_| panic!("no entity deleted"),
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_join_atomic_entities() {
+    use crate::join::ParJoin;
+    use rayon::iter::ParallelIterator;
+
+    let mut world = World::new();
+    let a = world.create_entity().build();
+    let entities = world.entities();
+    let b = entities.create();
+
+    let mut all: Vec<Entity> = (&entities).par_join().collect();
+    all.sort();
+    assert_eq!(all, vec![a, b]);
+}
+
 #[test]
 fn lazy_insertion() {
     let mut world = World::new();
@@ -164,3 +811,94 @@ fn delete_and_lazy() {
 
     world.delete_all();
 }
+
+#[test]
+fn null_entity() {
+    let mut world = World::new();
+    world.register::<Pos>();
+    world.create_entity().with(Pos).build();
+    let null = Entity::null();
+
+    assert!(!world.is_alive(null));
+    assert!(!world.entities().is_alive(null));
+    assert!(world.entities().delete(null).is_err());
+    assert!(world.delete_entity(null).is_err());
+    assert!(world.read_storage::<Pos>().get(null).is_none());
+    assert!(world.write_storage::<Pos>().insert(null, Pos).is_err());
+
+    world.maintain();
+    assert_eq!(world.entities().join().count(), 1);
+}
+
+#[test]
+fn create_entity_at_out_of_range() {
+    let mut world = World::new();
+    world.create_entity().build();
+
+    assert_eq!(world.create_entity_at(1 << 24), None);
+    assert_eq!(world.create_entity_at(Index::MAX), None);
+    assert_eq!(world.entities().max_allocated_id(), 1);
+}
+
+#[test]
+fn run_system_once() {
+    struct Spawn;
+
+    impl<'a> shred::System<'a> for Spawn {
+        type SystemData = (Entities<'a>, crate::WriteStorage<'a, Health>);
+
+        fn run(&mut self, (entities, mut health): Self::SystemData) {
+            let e = entities.create();
+            health.insert(e, Health(10)).unwrap();
+        }
+    }
+
+    let mut world = World::new();
+    world.run_system_once(Spawn);
+
+    let health = world.read_storage::<Health>();
+    assert_eq!(
+        (&health).join().cloned().collect::<Vec<_>>(),
+        vec![Health(10)]
+    );
+}
+
+#[test]
+fn maintain_and_apply() {
+    let mut world = World::new();
+    world.register::<Health>();
+
+    let dead = world.create_entity().build();
+    world.delete_entity(dead).unwrap();
+    let (a, b) = {
+        let entities = world.entities();
+        (entities.create(), entities.create())
+    };
+    world.maintain_and_apply(vec![(a, Health(1)), (b, Health(2)), (dead, Health(3))]);
+
+    let health = world.read_storage::<Health>();
+    assert_eq!(health.get(a), Some(&Health(1)));
+    assert_eq!(health.get(b), Some(&Health(2)));
+    assert_eq!(health.count(), 2);
+}
+
+#[test]
+fn drain_entities() {
+    let (mut world, entities) = health_world(&[1, 2, 3, 4]);
+
+    let drained = world.drain_entities(|e| e.id() % 2 == 0);
+    assert_eq!(drained, vec![entities[0], entities[2]]);
+    for &e in &drained {
+        assert!(!world.is_alive(e));
+    }
+
+    assert_eq!(world.entities().join().count(), 2);
+    assert_eq!(
+        world
+            .read_storage::<Health>()
+            .join()
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![Health(2), Health(4)]
+    );
+}
@@ -1,15 +1,21 @@
 use super::{
     comp::Component,
-    entity::{Allocator, EntitiesRes, Entity},
-    CreateIter, EntityBuilder, LazyUpdate,
+    entity::{Allocator, EntitiesRes, Entity, Index},
+    Builder, CreateIter, EntityBuilder, LazyUpdate, TypeRegistry,
 };
 
 use crate::{
-    error::WrongGeneration,
-    storage::{AnyStorage, MaskedStorage},
+    error::{ComponentExists, Error, WrongGeneration},
+    storage::{register_meta, AccessMut, AnyStorage, AnyStorageExt, MaskedStorage},
     ReadStorage, WriteStorage,
 };
-use shred::{Fetch, FetchMut, MetaTable, Read, Resource, SystemData, World};
+use ahash::AHashSet as HashSet;
+use shred::{Fetch, FetchMut, MetaTable, Read, Resource, RunNow, System, SystemData, World};
+use std::{
+    any::{Any, TypeId},
+    fmt,
+    sync::Mutex,
+};
 
 /// This trait provides some extension methods to make working with shred's
 /// [World] easier.
@@ -40,6 +46,9 @@ use shred::{Fetch, FetchMut, MetaTable, Read, Resource, SystemData, World};
 /// to execute code at the end of the frame, which is done in
 /// `World::maintain`.
 ///
+/// Additionally, a `TypeRegistry` keeps track of the names of all
+/// registered components.
+///
 /// ## Examples
 ///
 /// ```
@@ -140,6 +149,18 @@ pub trait WorldExt {
         F: FnOnce() -> T::Storage,
         T: Component;
 
+    /// Removes the storage of component `T`, dropping all of its components,
+    /// and removes `T` from the `TypeRegistry`. Returns `false` if `T` wasn't
+    /// registered.
+    ///
+    /// `T` can be registered again afterwards. This is meant for plugins
+    /// which install and uninstall component types at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the storage is currently borrowed.
+    fn deregister<T: Component>(&mut self) -> bool;
+
     /// Adds a resource to the world.
     ///
     /// If the resource already exists it will be overwritten.
@@ -196,6 +217,169 @@ pub trait WorldExt {
         self.write_component()
     }
 
+    /// Returns the number of entities which currently have a component of
+    /// type `T`.
+    ///
+    /// This counts the bits set in the storage's mask, so it doesn't need to
+    /// touch any component data.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if it is already borrowed mutably.
+    /// Panics if the component has not been registered.
+    fn component_count<T: Component>(&self) -> usize;
+
+    /// Calls `f` with the component of type `T` of `entity`, returning its
+    /// result, or `None` if the entity has no such component. The storage is
+    /// only borrowed for the duration of the call.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is borrowed mutably or `T` is not registered.
+    fn with_component<T, R, F>(&self, entity: Entity, f: F) -> Option<R>
+    where
+        T: Component,
+        F: FnOnce(&T) -> R;
+
+    /// Like `with_component`, but gives mutable access to the component.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is already borrowed or `T` is not registered.
+    fn with_component_mut<T, R, F>(&self, entity: Entity, f: F) -> Option<R>
+    where
+        T: Component,
+        F: FnOnce(&mut T) -> R;
+
+    /// Removes the component of type `T` from `entity`, returning it if there
+    /// was one.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is already borrowed or `T` is not registered.
+    fn remove_component<T: Component>(&self, entity: Entity) -> Option<T>;
+
+    /// Removes the component of type `T` from all entities by clearing its
+    /// storage.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is already borrowed or `T` is not registered.
+    fn clear_component<T: Component>(&self);
+
+    /// Exchanges the components of type `T` of `a` and `b`. If only one of
+    /// them has the component, it is moved to the other one. Swapping an
+    /// entity with itself does nothing.
+    ///
+    /// Returns an error if either entity is dead.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is already borrowed or `T` is not registered.
+    fn swap_components<T: Component>(&self, a: Entity, b: Entity) -> Result<(), Error>;
+
+    /// Returns `true` if the component of type `T` is attached to `entity`.
+    /// Only the component mask is checked, the component itself is not
+    /// accessed.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is borrowed mutably or `T` is not registered.
+    fn has_component<T: Component>(&self, entity: Entity) -> bool;
+
+    /// Converts every component of type `Old` into one of type `New` using
+    /// `f`, moving it to the `New` storage.
+    ///
+    /// Both storages are borrowed for the whole migration. Existing `New`
+    /// components of migrated entities are overwritten.
+    ///
+    /// If `f` panics, the migration stops partway: components converted so
+    /// far stay in the `New` storage, the remaining ones stay in the `Old`
+    /// storage and the component passed to `f` is dropped.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if either storage is already borrowed, `Old` and `New` are the
+    /// same type or either one is not registered. These checks happen
+    /// before any component is migrated.
+    fn migrate_component<Old, New, F>(&self, f: F)
+    where
+        Old: Component,
+        New: Component,
+        F: FnMut(Old) -> New;
+
+    /// Calls `f` with the component identified by `type_id` of `entity`,
+    /// returning its result, or `None` if the entity is dead, has no such
+    /// component or the component type is not registered.
+    ///
+    /// This allows accessing components without knowing their type at
+    /// compile time; `f` can use `Any::downcast_ref` to get the concrete
+    /// type. The `TypeRegistry` can be used to look up type names.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if a component storage is borrowed mutably.
+    fn with_component_dyn<R, F>(&self, entity: Entity, type_id: TypeId, f: F) -> Option<R>
+    where
+        F: FnOnce(&dyn Any) -> R;
+
+    /// Returns a pointer to the component identified by `type_id` of `entity`,
+    /// like `with_component_dyn`.
+    ///
+    /// # Safety
+    ///
+    /// The storage is no longer borrowed once this returns, so the pointer
+    /// may only be dereferenced as long as the component is not removed or
+    /// modified and no mutable borrow of its storage exists. It has to be
+    /// cast to the component type identified by `type_id`.
+    unsafe fn get_component_raw(&self, entity: Entity, type_id: TypeId) -> Option<*const ()>;
+
+    /// Returns clones of all components of type `T` together with their
+    /// entities, sorted by entity index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the storage is borrowed mutably or `T` is not registered.
+    fn component_data<T: Component + Clone>(&self) -> Vec<(Entity, T)>;
+
+    /// Returns the `TypeId`s of all registered components, in the order they
+    /// were registered.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any component storage is borrowed mutably.
+    fn component_type_ids(&self) -> Vec<TypeId>;
+
+    /// Returns the number of registered component storages.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any component storage is borrowed mutably.
+    fn component_storage_count(&self) -> usize;
+
+    /// Returns the type names of all registered components, sorted
+    /// alphabetically. The names are taken from the `TypeRegistry`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the `TypeRegistry` is borrowed mutably.
+    fn component_names(&self) -> Vec<&'static str>;
+
+    /// Asserts that both worlds have the same alive entities and contain equal
+    /// components of type `T` for them, panicking with a list of all
+    /// differences otherwise.
+    ///
+    /// This is meant for tests comparing the state of a world against an
+    /// expected one.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the entities or components differ, or if the entities or
+    /// either storage are borrowed mutably.
+    fn assert_components_eq<T>(&self, other: &World)
+    where
+        T: Component + PartialEq + fmt::Debug;
+
     /// Fetches a resource for reading.
     ///
     /// ## Panics
@@ -212,6 +396,106 @@ pub trait WorldExt {
     /// Panics if the resource has not been added.
     fn write_resource<T: Resource>(&self) -> FetchMut<T>;
 
+    /// Fetches a resource for reading, returning `None` if the resource has
+    /// not been added.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if it is already borrowed mutably.
+    fn try_read_resource<T: Resource>(&self) -> Option<Fetch<T>>;
+
+    /// Fetches a resource for writing, returning `None` if the resource has
+    /// not been added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is already borrowed.
+    fn try_write_resource<T: Resource>(&self) -> Option<FetchMut<T>>;
+
+    /// Fetches a resource for writing, inserting the result of `f` first if
+    /// it has not been added yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource is already borrowed.
+    fn resource_or_insert_with<T, F>(&mut self, f: F) -> FetchMut<T>
+    where
+        T: Resource,
+        F: FnOnce() -> T;
+
+    /// Fetches a resource for writing, inserting `T::default()` first if it
+    /// has not been added yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource is already borrowed.
+    fn resource_or_default<T: Resource + Default>(&mut self) -> FetchMut<T>;
+
+    /// Clones the resource `T` from `src` into this world, overwriting any
+    /// existing value.
+    ///
+    /// Copying all resources at once is not supported since `shred` doesn't
+    /// allow enumerating them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` doesn't contain the resource or it is borrowed
+    /// mutably.
+    fn copy_resource<T: Resource + Clone>(&mut self, src: &World);
+
+    /// Marks the resource `T` as changed, so `resource_changed::<T>()` returns
+    /// `true` until the next `maintain()`.
+    ///
+    /// Change tracking is explicit: fetching a resource for writing doesn't
+    /// mark it, so call this after modifying a resource that others should be
+    /// notified about. Only worlds created with `WorldExt::new` track changes.
+    fn mark_resource_changed<T: Resource>(&self);
+
+    /// Returns `true` if the resource `T` has been marked with
+    /// `mark_resource_changed` since the last `maintain()`.
+    fn resource_changed<T: Resource>(&self) -> bool;
+
+    /// Borrows a resource mutably for the duration of `f`, returning its
+    /// result. The borrow is released as soon as `f` returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource has not been added or is already borrowed.
+    fn with_resource<T, R, F>(&self, f: F) -> R
+    where
+        T: Resource,
+        F: FnOnce(&mut T) -> R;
+
+    /// Like `with_resource`, but inserts `T::default()` first if the resource
+    /// has not been added yet.
+    fn with_or_default<T, R, F>(&mut self, f: F) -> R
+    where
+        T: Resource + Default,
+        F: FnOnce(&mut T) -> R;
+
+    /// Returns a wrapper implementing `Debug` which summarizes the contents of
+    /// this `World`: the number of live entities and every registered
+    /// component together with the number of entities it is attached to.
+    ///
+    /// `World` itself is defined in `shred`, which is why this can't be a
+    /// `Debug` impl on `World` directly. Resources are not listed since
+    /// `shred` doesn't allow enumerating them.
+    ///
+    /// ## Panics
+    ///
+    /// Formatting panics if `EntitiesRes` or any component storage is
+    /// borrowed mutably.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use specs::prelude::*;
+    ///
+    /// let world = World::new();
+    /// println!("{:?}", world.debug());
+    /// ```
+    fn debug(&self) -> DebugWorld<'_>;
+
     /// Convenience method for fetching entities.
     ///
     /// Creation and deletion of entities with the `Entities` struct
@@ -219,9 +503,25 @@ pub trait WorldExt {
     /// with the next call to `maintain()`.
     fn entities(&self) -> Read<EntitiesRes>;
 
-    /// Convenience method for fetching entities.
+    /// Convenience method for fetching entities mutably.
+    ///
+    /// This allows creating entities immediately with
+    /// `EntitiesRes::allocate`, without a `maintain()` cycle, which is mostly
+    /// useful for setup code outside of systems.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entities are already borrowed, e.g. by a storage that
+    /// is still being held or a system that is currently running.
     fn entities_mut(&self) -> FetchMut<EntitiesRes>;
 
+    /// Creates an entity at the given index, returning `None` if an entity
+    /// with that index is already alive or has been created atomically, or
+    /// if the index is larger than the entity bit sets can hold.
+    ///
+    /// This is mostly useful for interoperation, e.g. when loading save files.
+    fn create_entity_at(&mut self, index: Index) -> Option<Entity>;
+
     /// Allows building an entity with its components.
     ///
     /// This takes a mutable reference to the `World`, since no
@@ -255,6 +555,11 @@ pub trait WorldExt {
     /// ```
     fn create_iter(&mut self) -> CreateIter;
 
+    /// Creates `n` entities at once, which are alive immediately.
+    ///
+    /// This is a shorthand for `create_iter().take(n).collect()`.
+    fn create_entity_batch(&mut self, n: usize) -> Vec<Entity>;
+
     /// Deletes an entity and its components.
     fn delete_entity(&mut self, entity: Entity) -> Result<(), WrongGeneration>;
 
@@ -268,6 +573,44 @@ pub trait WorldExt {
     /// Deletes all entities and their components.
     fn delete_all(&mut self);
 
+    /// Deletes all entities for which `f` returns `true`, together with their
+    /// components, and returns them.
+    ///
+    /// Unlike `EntitiesRes::kill_if`, the entities are deleted immediately
+    /// instead of on the next `maintain`.
+    fn drain_entities<F>(&mut self, f: F) -> Vec<Entity>
+    where
+        F: FnMut(Entity) -> bool;
+
+    /// Moves the component of type `T` from `src` to `dst`.
+    ///
+    /// Returns `Ok(true)` if the component has been moved and `Ok(false)` if
+    /// `src` has no such component.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::WrongGeneration` if either entity is dead and
+    /// `Error::ComponentExists` if `dst` already has a component of type
+    /// `T`. Nothing is changed in both cases.
+    fn move_component<T: Component>(&self, src: Entity, dst: Entity) -> Result<bool, Error>;
+
+    /// Moves `entity` with all of its components into `target`, returning
+    /// the newly created entity in `target` and deleting `entity` from this
+    /// world.
+    ///
+    /// Components whose type is not registered in `target` are dropped and
+    /// a warning is logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `WrongGeneration` error if `entity` is dead; neither world
+    /// is changed in that case.
+    fn transfer_entity(
+        &mut self,
+        entity: Entity,
+        target: &mut World,
+    ) -> Result<Entity, WrongGeneration>;
+
     /// Checks if an entity is alive.
     /// Please note that atomically created or deleted entities
     /// (the ones created / deleted with the `Entities` struct)
@@ -279,11 +622,20 @@ pub trait WorldExt {
     /// you are most likely in a system; from there, just access the
     /// `Entities` resource and call the `is_alive` method.
     ///
+    /// Returns `false` for `Entity::null()`.
+    ///
     /// # Panics
     ///
-    /// Panics if generation is dead.
+    /// Panics if generation is dead (except for the null entity).
     fn is_alive(&self, e: Entity) -> bool;
 
+    /// Panics if any of the given entities is no longer alive, e.g. because
+    /// it was deleted before the last `maintain()`.
+    ///
+    /// This is meant for catching stale entity handles during development
+    /// and does nothing unless debug assertions are enabled.
+    fn assert_no_dangling_references(&self, entities: &[Entity]);
+
     /// Merges in the appendix, recording all the dynamically created
     /// and deleted entities into the persistent generations vector.
     /// Also removes all the abandoned components.
@@ -291,6 +643,60 @@ pub trait WorldExt {
     /// Additionally, `LazyUpdate` will be merged.
     fn maintain(&mut self);
 
+    /// Like `maintain`, but calls `on_create` for every entity that was
+    /// created atomically and `on_delete` for every entity that was deleted
+    /// atomically since the last maintain.
+    ///
+    /// `on_delete` is called after the components of the deleted entities
+    /// have been removed. Entities created or deleted directly through the
+    /// `World` (e.g. with `create_entity`) take effect immediately and are
+    /// not reported.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use specs::prelude::*;
+    ///
+    /// let mut world = World::new();
+    /// let e = world.entities().create();
+    ///
+    /// let mut created = vec![];
+    /// world.maintain_with_hooks(|e| created.push(e), |_| {});
+    /// assert_eq!(created, vec![e]);
+    /// ```
+    fn maintain_with_hooks<C, D>(&mut self, on_create: C, on_delete: D)
+    where
+        C: FnMut(Entity),
+        D: FnMut(Entity);
+
+    /// Returns the entities that the next `maintain` would create and delete
+    /// (as reported to the hooks of `maintain_with_hooks`), without applying
+    /// any changes.
+    ///
+    /// Changes queued in `LazyUpdate` are not taken into account.
+    fn maintain_dry_run(&self) -> (Vec<Entity>, Vec<Entity>);
+
+    /// Calls `maintain` and then inserts all the given components. This is
+    /// useful for attaching components to entities that were created
+    /// atomically, which only become alive after `maintain`.
+    ///
+    /// Components for entities that are not alive after `maintain` are
+    /// dropped and a warning is logged.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `T` is not registered.
+    fn maintain_and_apply<T: Component>(&mut self, deferred: Vec<(Entity, T)>);
+
+    /// Sets up and runs `system` a single time, then calls `maintain`.
+    ///
+    /// This is meant for one-shot systems, e.g. for initialization, that
+    /// don't need to be added to a dispatcher. The system is dropped
+    /// afterwards.
+    fn run_system_once<S>(&mut self, system: S)
+    where
+        S: for<'a> System<'a>;
+
     #[doc(hidden)]
     fn delete_components(&mut self, delete: &[Entity]);
 }
@@ -300,7 +706,10 @@ impl WorldExt for World {
         let mut world = Self::default();
         world.insert(EntitiesRes::default());
         world.insert(MetaTable::<dyn AnyStorage>::default());
+        world.insert(MetaTable::<dyn AnyStorageExt>::default());
         world.insert(LazyUpdate::default());
+        world.insert(TypeRegistry::default());
+        world.insert(ChangedResources::default());
 
         world
     }
@@ -319,8 +728,16 @@ impl WorldExt for World {
     {
         self.entry()
             .or_insert_with(move || MaskedStorage::<T>::new(storage()));
-        self.fetch_mut::<MetaTable<dyn AnyStorage>>()
-            .register::<MaskedStorage<T>>();
+        register_meta::<T>(self);
+    }
+
+    fn deregister<T: Component>(&mut self) -> bool {
+        if let Some(mut registry) = self.try_fetch_mut::<TypeRegistry>() {
+            registry.unregister::<T>();
+        }
+
+        // The `MetaTable` entry is kept; it skips storages which are missing.
+        self.remove::<MaskedStorage<T>>().is_some()
     }
 
     fn add_resource<T: Resource>(&mut self, res: T) {
@@ -335,6 +752,155 @@ impl WorldExt for World {
         self.system_data()
     }
 
+    fn component_count<T: Component>(&self) -> usize {
+        self.read_storage::<T>().count()
+    }
+
+    fn with_component<T, R, F>(&self, entity: Entity, f: F) -> Option<R>
+    where
+        T: Component,
+        F: FnOnce(&T) -> R,
+    {
+        self.read_storage::<T>().get(entity).map(f)
+    }
+
+    fn with_component_mut<T, R, F>(&self, entity: Entity, f: F) -> Option<R>
+    where
+        T: Component,
+        F: FnOnce(&mut T) -> R,
+    {
+        self.write_storage::<T>()
+            .get_mut(entity)
+            .map(|mut component| f(component.access_mut()))
+    }
+
+    fn remove_component<T: Component>(&self, entity: Entity) -> Option<T> {
+        self.write_storage::<T>().remove(entity)
+    }
+
+    fn clear_component<T: Component>(&self) {
+        self.write_storage::<T>().clear();
+    }
+
+    fn swap_components<T: Component>(&self, a: Entity, b: Entity) -> Result<(), Error> {
+        self.write_storage::<T>().swap(a, b)
+    }
+
+    fn has_component<T: Component>(&self, entity: Entity) -> bool {
+        self.read_storage::<T>().contains(entity)
+    }
+
+    fn migrate_component<Old, New, F>(&self, mut f: F)
+    where
+        Old: Component,
+        New: Component,
+        F: FnMut(Old) -> New,
+    {
+        use crate::join::Join;
+
+        let entities = self.entities();
+        let mut old = self.write_storage::<Old>();
+        let mut new = self.write_storage::<New>();
+
+        for (entity, component) in (&entities, old.drain()).join() {
+            new.insert(entity, f(component))
+                .expect("Bug: joined entity is not alive");
+        }
+    }
+
+    fn with_component_dyn<R, F>(&self, entity: Entity, type_id: TypeId, f: F) -> Option<R>
+    where
+        F: FnOnce(&dyn Any) -> R,
+    {
+        if !self.entities().is_alive(entity) {
+            return None;
+        }
+
+        let table = self.fetch::<MetaTable<dyn AnyStorageExt>>();
+        let storage = table
+            .iter(self)
+            .find(|storage| storage.component_type_id() == type_id)?;
+        let component = storage.get_any(entity.id())?;
+        Some(f(component))
+    }
+
+    unsafe fn get_component_raw(&self, entity: Entity, type_id: TypeId) -> Option<*const ()> {
+        self.with_component_dyn(entity, type_id, |component| {
+            component as *const dyn Any as *const ()
+        })
+    }
+
+    fn component_data<T: Component + Clone>(&self) -> Vec<(Entity, T)> {
+        self.read_storage::<T>()
+            .iter()
+            .map(|(entity, component)| (entity, component.clone()))
+            .collect()
+    }
+
+    fn component_type_ids(&self) -> Vec<TypeId> {
+        self.fetch::<MetaTable<dyn AnyStorageExt>>()
+            .iter(self)
+            .map(|storage| storage.component_type_id())
+            .collect()
+    }
+
+    fn component_storage_count(&self) -> usize {
+        self.fetch::<MetaTable<dyn AnyStorage>>().iter(self).count()
+    }
+
+    fn component_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = self
+            .try_fetch::<TypeRegistry>()
+            .map(|registry| registry.iter().map(|(_, name)| name).collect())
+            .unwrap_or_default();
+        names.sort_unstable();
+        names
+    }
+
+    fn assert_components_eq<T>(&self, other: &World)
+    where
+        T: Component + PartialEq + fmt::Debug,
+    {
+        use crate::join::Join;
+
+        let left = self.read_storage::<T>();
+        let right = other.read_storage::<T>();
+
+        let mut diff = Vec::new();
+        let left_entities: HashSet<_> = self.entities().join().collect();
+        let right_entities: HashSet<_> = other.entities().join().collect();
+        for entity in self.entities().join() {
+            if !right_entities.contains(&entity) {
+                diff.push(format!("{:?} is only alive on the left", entity));
+            }
+        }
+        for entity in other.entities().join() {
+            if !left_entities.contains(&entity) {
+                diff.push(format!("{:?} is only alive on the right", entity));
+            }
+        }
+
+        for (entity, l) in left.iter() {
+            match right.get(entity) {
+                Some(r) if l == r => {}
+                Some(r) => diff.push(format!("{:?}: {:?} != {:?}", entity, l, r)),
+                None => diff.push(format!("{:?}: {:?} is missing on the right", entity, l)),
+            }
+        }
+        for (entity, r) in right.iter() {
+            if !left.contains(entity) {
+                diff.push(format!("{:?}: {:?} is missing on the left", entity, r));
+            }
+        }
+
+        assert!(
+            diff.is_empty(),
+            "Worlds differ in components of type `{}`:\n  {}",
+            std::any::type_name::<T>(),
+            diff.join("\n  ")
+        );
+    }
+
     fn read_resource<T: Resource>(&self) -> Fetch<T> {
         self.fetch()
     }
@@ -343,12 +909,81 @@ impl WorldExt for World {
         self.fetch_mut()
     }
 
+    fn try_read_resource<T: Resource>(&self) -> Option<Fetch<T>> {
+        self.try_fetch()
+    }
+
+    fn try_write_resource<T: Resource>(&self) -> Option<FetchMut<T>> {
+        self.try_fetch_mut()
+    }
+
+    fn resource_or_insert_with<T, F>(&mut self, f: F) -> FetchMut<T>
+    where
+        T: Resource,
+        F: FnOnce() -> T,
+    {
+        self.entry::<T>().or_insert_with(f)
+    }
+
+    fn resource_or_default<T: Resource + Default>(&mut self) -> FetchMut<T> {
+        self.resource_or_insert_with(T::default)
+    }
+
+    fn copy_resource<T: Resource + Clone>(&mut self, src: &World) {
+        let res = T::clone(&src.fetch::<T>());
+        self.insert(res);
+    }
+
+    fn mark_resource_changed<T: Resource>(&self) {
+        if let Some(changed) = self.try_fetch::<ChangedResources>() {
+            changed
+                .0
+                .lock()
+                .expect("poisoned resource change set")
+                .insert(TypeId::of::<T>());
+        }
+    }
+
+    fn resource_changed<T: Resource>(&self) -> bool {
+        self.try_fetch::<ChangedResources>().is_some_and(|changed| {
+            changed
+                .0
+                .lock()
+                .expect("poisoned resource change set")
+                .contains(&TypeId::of::<T>())
+        })
+    }
+
+    fn with_resource<T, R, F>(&self, f: F) -> R
+    where
+        T: Resource,
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut self.write_resource::<T>())
+    }
+
+    fn with_or_default<T, R, F>(&mut self, f: F) -> R
+    where
+        T: Resource + Default,
+        F: FnOnce(&mut T) -> R,
+    {
+        f(&mut self.resource_or_insert_with::<T, _>(Default::default))
+    }
+
+    fn debug(&self) -> DebugWorld<'_> {
+        DebugWorld(self)
+    }
+
     fn entities(&self) -> Read<EntitiesRes> {
         Read::fetch(self)
     }
 
     fn entities_mut(&self) -> FetchMut<EntitiesRes> {
-        self.write_resource()
+        self.fetch_mut()
+    }
+
+    fn create_entity_at(&mut self, index: Index) -> Option<Entity> {
+        self.entities_mut().alloc.allocate_at(index)
     }
 
     fn create_entity(&mut self) -> EntityBuilder {
@@ -369,6 +1004,10 @@ impl WorldExt for World {
         CreateIter(self.entities_mut())
     }
 
+    fn create_entity_batch(&mut self, n: usize) -> Vec<Entity> {
+        self.create_iter().take(n).collect()
+    }
+
     fn delete_entity(&mut self, entity: Entity) -> Result<(), WrongGeneration> {
         self.delete_entities(&[entity])
             .map_err(|(wrong_gen, _)| wrong_gen)
@@ -396,26 +1035,187 @@ impl WorldExt for World {
         );
     }
 
+    fn drain_entities<F>(&mut self, mut f: F) -> Vec<Entity>
+    where
+        F: FnMut(Entity) -> bool,
+    {
+        use crate::join::Join;
+
+        let entities: Vec<_> = self.entities().join().filter(|&e| f(e)).collect();
+
+        self.delete_entities(&entities).expect(
+            "Bug: previously collected entities are not valid \
+             even though access should be exclusive",
+        );
+
+        entities
+    }
+
+    fn move_component<T: Component>(&self, src: Entity, dst: Entity) -> Result<bool, Error> {
+        let entities = self.entities();
+        for &entity in &[src, dst] {
+            if !entities.is_alive(entity) {
+                return Err(WrongGeneration {
+                    action: "move component of",
+                    actual_gen: entities.entity(entity.id()).gen(),
+                    entity,
+                }
+                .into());
+            }
+        }
+
+        let mut storage = self.write_storage::<T>();
+        if storage.contains(dst) {
+            return Err(ComponentExists {
+                component: std::any::type_name::<T>(),
+                entity: dst,
+            }
+            .into());
+        }
+
+        match storage.remove(src) {
+            Some(component) => {
+                storage
+                    .insert(dst, component)
+                    .expect("Bug: `dst` was checked to be alive");
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn transfer_entity(
+        &mut self,
+        entity: Entity,
+        target: &mut World,
+    ) -> Result<Entity, WrongGeneration> {
+        {
+            let entities = self.entities();
+            if !entities.is_alive(entity) {
+                return Err(WrongGeneration {
+                    action: "transfer",
+                    actual_gen: entities.entity(entity.id()).gen(),
+                    entity,
+                });
+            }
+        }
+
+        let new_entity = target.create_entity().build();
+        for mut storage in self
+            .fetch_mut::<MetaTable<dyn AnyStorageExt>>()
+            .iter_mut(self)
+        {
+            (*storage).move_to(entity, target, new_entity);
+        }
+        self.delete_entity(entity)?;
+
+        Ok(new_entity)
+    }
+
     fn is_alive(&self, e: Entity) -> bool {
+        if e.is_null() {
+            return false;
+        }
         assert!(e.gen().is_alive(), "Generation is dead");
 
         let alloc: &Allocator = &self.entities().alloc;
         alloc.generation(e.id()) == Some(e.gen())
     }
 
+    fn assert_no_dangling_references(&self, entities: &[Entity]) {
+        if cfg!(debug_assertions) {
+            let alive = self.entities();
+            for &entity in entities {
+                assert!(alive.is_alive(entity), "Dangling reference to {:?}", entity);
+            }
+        }
+    }
+
     fn maintain(&mut self) {
-        let deleted = self.entities_mut().alloc.merge();
+        self.maintain_with_hooks(|_| {}, |_| {});
+    }
+
+    fn maintain_dry_run(&self) -> (Vec<Entity>, Vec<Entity>) {
+        self.entities().alloc.pending()
+    }
+
+    fn maintain_and_apply<T: Component>(&mut self, deferred: Vec<(Entity, T)>) {
+        self.maintain();
+        self.write_storage::<T>().extend(deferred);
+    }
+
+    fn maintain_with_hooks<C, D>(&mut self, on_create: C, on_delete: D)
+    where
+        C: FnMut(Entity),
+        D: FnMut(Entity),
+    {
+        if let Some(mut changed) = self.try_fetch_mut::<ChangedResources>() {
+            changed
+                .0
+                .get_mut()
+                .expect("poisoned resource change set")
+                .clear();
+        }
+
+        let deleted = self.entities_mut().alloc.merge_with(on_create);
         if !deleted.is_empty() {
             self.delete_components(&deleted);
+            deleted.into_iter().for_each(on_delete);
         }
 
-        let lazy = self.write_resource::<LazyUpdate>().clone();
+        let lazy = LazyUpdate::clone(&self.fetch::<LazyUpdate>());
         lazy.maintain(self);
     }
 
+    fn run_system_once<S>(&mut self, mut system: S)
+    where
+        S: for<'a> System<'a>,
+    {
+        RunNow::setup(&mut system, self);
+        system.run_now(self);
+        self.maintain();
+    }
+
     fn delete_components(&mut self, delete: &[Entity]) {
         for mut storage in self.fetch_mut::<MetaTable<dyn AnyStorage>>().iter_mut(self) {
             (*storage).drop(delete);
         }
     }
 }
+
+/// The resources marked as changed since the last `maintain()`.
+#[derive(Default)]
+struct ChangedResources(Mutex<HashSet<TypeId>>);
+
+/// `Debug` wrapper for a `World`, see `WorldExt::debug`.
+pub struct DebugWorld<'a>(&'a World);
+
+impl<'a> fmt::Debug for DebugWorld<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use crate::join::Join;
+
+        let entities = self.0.entities().join().count();
+        let mut components: Vec<(&'static str, usize)> = self
+            .0
+            .fetch::<MetaTable<dyn AnyStorageExt>>()
+            .iter(self.0)
+            .map(|storage| (storage.component_name(), storage.count()))
+            .collect();
+        components.sort_unstable();
+
+        f.debug_struct("World")
+            .field("entities", &entities)
+            .field("components", &DebugComponents(&components))
+            .finish()
+    }
+}
+
+struct DebugComponents<'a>(&'a [(&'static str, usize)]);
+
+impl<'a> fmt::Debug for DebugComponents<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|&(name, count)| (name, count)))
+            .finish()
+    }
+}